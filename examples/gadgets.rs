@@ -8,23 +8,27 @@ fn panic(_: &PanicInfo) -> ! {
 }
 
 use nanos_sdk::buttons::*;
+use nanos_ui::event::{Event, SEPH_PACKET_LEN};
 use nanos_ui::layout::{Layout, Location, StringPlace};
 use nanos_ui::ui;
+use nanos_ui::ui::GadgetExt;
 
 fn wait_any() {
     let mut buttons = ButtonsState::new();
+    let mut packet = [0u8; SEPH_PACKET_LEN];
     loop {
-        match ui::get_event(&mut buttons) {
-            Some(ButtonEvent::LeftButtonRelease)
-            | Some(ButtonEvent::RightButtonRelease)
-            | Some(ButtonEvent::BothButtonsRelease) => return,
-            _ => (),
+        if let Some(Event::Release(_)) = ui::get_event(&mut buttons, &mut packet) {
+            return;
         }
     }
 }
 
 #[no_mangle]
 extern "C" fn sample_main() {
+    // Long presses, timeouts and the debounce are counted with ticker events
+    #[cfg(not(feature = "speculos"))]
+    nanos_ui::event::setup_ticker(100);
+
     ui::clear_screen();
     ui::popup("Hello");
 
@@ -64,29 +68,132 @@ extern "C" fn sample_main() {
     let scrolled_message = "Arbitrary long text goes here, with numbers -1234567890";
     ui::MessageScroller::new(scrolled_message).event_loop();
 
-    loop {
-        match ui::Menu::new(&[&"Top0", &"Top1", &"Top2", &"Top3", &"Next"]).show() {
-            0 => loop {
-                match ui::Menu::new(&[&"Top0_sub0", &"Back"]).show() {
-                    0 => ui::popup("Top0_sub0_0"),
-                    _ => break,
-                }
-            },
-            1 => loop {
-                match ui::Menu::new(&[&"Top1_sub0", &"Top1_sub1", &"Back"]).show() {
-                    0 => ui::popup("Top1_sub0_0"),
-                    1 => ui::popup("Top1_sub1_0"),
-                    _ => break,
-                }
-            },
-            2 => break,
-            3 => break,
-            4 => break,
-            _ => (),
+    let address = "0xde0B295669a9FD93d5F28D9Ec85E40f4cb697BAe";
+    ui::AddressDisplay::new(address)
+        .bold_ends(true)
+        .event_loop();
+
+    use nanos_ui::menu::{Action, Entry, MenuTree};
+    use ui::MenuItem;
+    const TOP0: [Entry; 1] = [Entry::new(
+        MenuItem::new("Top0_sub0"),
+        Action::Call(|| ui::popup("Top0_sub0_0")),
+    )];
+    const TOP1: [Entry; 2] = [
+        Entry::new(
+            MenuItem::new("Top1_sub0"),
+            Action::Call(|| ui::popup("Top1_sub0_0")),
+        ),
+        Entry::new(
+            MenuItem::new("Top1_sub1"),
+            Action::Call(|| ui::popup("Top1_sub1_0")),
+        ),
+    ];
+    const TOP: [Entry; 3] = [
+        Entry::new(MenuItem::new("Top0"), Action::Submenu(&TOP0)),
+        Entry::new(MenuItem::new("Top1"), Action::Submenu(&TOP1)),
+        Entry::new(MenuItem::new("Next"), Action::Exit),
+    ];
+    MenuTree::new(&TOP).show();
+
+    use nanos_ui::bitmaps;
+    let items = [
+        MenuItem::new("Application")
+            .second_line("is ready")
+            .icon(&bitmaps::CERTIFICATE),
+        MenuItem::new("Settings").icon(&bitmaps::COGGLE),
+        MenuItem::new("Quit").icon(&bitmaps::DASHBOARD),
+    ];
+    let _ = ui::Menu::from_items(&items).wrap(true).show();
+
+    let coins = [
+        "Bitcoin", "Ethereum", "Monero", "Zcash", "Solana", "Polkadot",
+    ];
+    let _ = ui::Menu::new(&coins)
+        .initial(2)
+        .sliding_window(true)
+        .scroll_indicator(true)
+        .show();
+
+    // 100 accounts, formatted only when displayed
+    struct Accounts;
+    impl ui::MenuSource for Accounts {
+        fn len(&self) -> usize {
+            100
+        }
+        fn item<'b>(&'b self, index: usize, buf: &'b mut [u8; ui::ITEM_BUF_LEN]) -> &'b str {
+            ui::ItemWriter::new(buf)
+                .str("Account #")
+                .num(index)
+                .finish()
         }
     }
+    let _ = ui::Menu::from_source(&Accounts).sliding_window(true).show();
+
+    use ui::ItemKind;
+    let networks = [
+        ("Mainnets", ItemKind::Header),
+        ("Ethereum", ItemKind::Selectable),
+        ("Polygon", ItemKind::Disabled),
+        ("Testnets", ItemKind::Header),
+        ("Sepolia", ItemKind::Selectable),
+    ];
+    let _ = ui::Menu::from_source(&networks).show();
+
+    let _account = nanos_ui::picker::NumberPicker::new(0, 1000)
+        .title("Account index")
+        .show();
+
+    let mut name = [0u8; 16];
+    let len = nanos_ui::entry::TextEntry::new(&mut name)
+        .title("Account name")
+        .show();
+    if let Ok(name) = core::str::from_utf8(&name[..len]) {
+        ui::popup(name);
+    }
+
+    {
+        // Zeroised at the end of the block
+        let mut passphrase = nanos_ui::entry::SecretEntry::<32>::new().title("Passphrase");
+        let _passphrase = passphrase.show();
+    }
+
+    // Any sorted wordlist, e.g. the 2048 BIP39 words
+    const WORDS: [&str; 8] = [
+        "abandon", "ability", "able", "about", "above", "absent", "absorb", "abstract",
+    ];
+    let _word = nanos_ui::words::WordPicker::new(&WORDS)
+        .title("Enter word #1")
+        .menu_threshold(3)
+        .show();
+
+    let mnemonic = ["abandon", "ability", "able", "about", "above", "absent"];
+    nanos_ui::words::MnemonicDisplay::new(&mnemonic).show();
+    if !nanos_ui::words::MnemonicQuiz::new(&mnemonic)
+        .rounds(2)
+        .check()
+    {
+        ui::popup("Wrong word");
+    }
+
+    use nanos_ui::settings::{Setting, Settings};
+    const SETTINGS: [Setting; 2] = [
+        Setting::toggle("Blind signing"),
+        Setting::choice("Display", &["Short", "Long", "Expert"]),
+    ];
+    let mut values = [0u8; 2];
+    Settings::new(&SETTINGS, &mut values).show();
 
     let _ = ui::Validator::new("Confirm?").ask();
+    // Reject automatically after 30 seconds of inactivity
+    if ui::Validator::new("Quick!")
+        .show_with_timeout(30_000)
+        .is_err()
+    {
+        ui::SingleMessage::new("Timed out")
+            .show_with_timeout(2_000)
+            .ok();
+    }
     let _ = ui::MessageValidator::new(
         &[&"Message Review"],
         &[&"Confirm", &"message?"],
@@ -94,6 +201,46 @@ extern "C" fn sample_main() {
     )
     .ask();
 
+    // The app can also own the event loop, to handle APDUs
+    // while a gadget is on screen
+    use ui::Gadget;
+    let mut review = ui::MessageValidator::new(&[&"Review"], &[&"Approve"], &[&"Reject"]);
+    review.render();
+    let mut buttons = ButtonsState::new();
+    let mut packet = [0u8; SEPH_PACKET_LEN];
+    let _approved = loop {
+        match ui::get_event(&mut buttons, &mut packet) {
+            Some(Event::Apdu) => (),        // process the command in `packet` here
+            Some(Event::UsbTransfer) => (), // hand `packet` to the USB stack here
+            Some(event) => {
+                if let Some(approved) = review.handle(event) {
+                    break approved;
+                }
+            }
+            None => (),
+        }
+    };
+
+    // Sequential flow, interleaved with APDU handling
+    use nanos_ui::executor::{select, Either, Events};
+    let events = Events::new();
+    let mut seph = ui::SephEvents::new();
+    let mut review = core::pin::pin!(ui::Validator::new("Sign?").show_async(&events));
+    let _approved = loop {
+        let apdu = events.next_where(|e| *e == Event::Apdu);
+        // Only the reference to the review is dropped when a
+        // command comes first, so the review stays on screen
+        match events.block_on(&mut seph, select(review.as_mut(), apdu)) {
+            Some(Either::Left(approved)) => break approved,
+            Some(Either::Right(_)) => {
+                // Process the command, e.g. the next chunk of the transaction
+                let _command = seph.packet();
+            }
+            // `SephEvents` never runs out
+            None => break false,
+        }
+    };
+
     ui::clear_screen();
 
     use nanos_ui::bagls::RectFull as Rect;
@@ -36,31 +36,63 @@ impl RectFull {
     pub const fn height(self, height: u32) -> RectFull {
         RectFull { height, ..self }
     }
-}
 
-const fn middle_y(glyph: &Glyph) -> i16 {
-    ((crate::SCREEN_HEIGHT as u32 - glyph.height) / 2) as i16
+    /// Rectangle touched by `display`
+    pub const fn bounds(&self) -> Area {
+        Area::new(
+            self.pos.0,
+            self.pos.1,
+            self.width as usize,
+            self.height as usize,
+        )
+    }
 }
 
+/// Icon at `pos`, built with `Icon::new`, or vertically centered
+/// on the current screen profile, built with `Icon::from`
 pub struct Icon<'a> {
     pub icon: &'a Glyph<'a>,
+    /// Top-left corner, or shift from the centered position
+    /// for the vertical coordinate of centered icons
     pub pos: (i16, i16),
+    centered: bool,
 }
 
 impl<'a> From<&'a Glyph<'a>> for Icon<'a> {
     fn from(glyph: &'a Glyph) -> Icon<'a> {
+        Icon::from(glyph)
+    }
+}
+
+impl<'a> Icon<'a> {
+    /// Icon with its top-left corner at `pos`
+    pub const fn new(glyph: &'a Glyph<'a>, pos: (i16, i16)) -> Icon<'a> {
         Icon {
             icon: glyph,
-            pos: (0, middle_y(glyph)),
+            pos,
+            centered: false,
         }
     }
-}
 
-impl<'a> Icon<'a> {
+    /// Icon on the left edge, vertically centered
     pub const fn from(glyph: &'a Glyph<'a>) -> Icon<'a> {
         Icon {
             icon: glyph,
-            pos: (0, middle_y(glyph)),
+            pos: (0, 0),
+            centered: true,
+        }
+    }
+
+    /// Top-left corner on the current screen profile
+    pub fn origin(&self) -> (i16, i16) {
+        match self.centered {
+            true => {
+                let icon = ledger_sdk_sys::pic_rs(self.icon);
+                let height = ScreenProfile::current().height as u32;
+                let middle = (height.saturating_sub(icon.height) / 2) as i16;
+                (self.pos.0, middle + self.pos.1)
+            }
+            false => self.pos,
         }
     }
 
@@ -76,6 +108,7 @@ impl<'a> Icon<'a> {
     pub const fn set_y(self, y: i16) -> Icon<'a> {
         Icon {
             pos: (self.pos.0, y),
+            centered: false,
             ..self
         }
     }
@@ -95,23 +128,106 @@ impl<'a> Icon<'a> {
             ..self
         }
     }
+
+    /// Rectangle touched by `display`
+    pub fn bounds(&self) -> Area {
+        let (x, y) = self.origin();
+        self.icon.bounds(x as i32, y as i32)
+    }
+}
+
+use crate::layout::{Area, Draw, Element};
+
+impl Element for RectFull {
+    fn size(&self) -> (usize, usize) {
+        (self.width as usize, self.height as usize)
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        RectFull::new()
+            .pos(x, y)
+            .width(self.width)
+            .height(self.height)
+            .display();
+    }
+}
+
+impl Element for Icon<'_> {
+    fn size(&self) -> (usize, usize) {
+        let icon = ledger_sdk_sys::pic_rs(self.icon);
+        (icon.width as usize, icon.height as usize)
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        Icon::new(self.icon, (x as i16, y as i16)).display();
+    }
 }
 
 use crate::bitmaps;
+use crate::screen::ScreenProfile;
+
+/// Navigation arrows, at their position on the current screen profile
+#[derive(Copy, Clone)]
+pub enum Arrow {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Arrow {
+    pub fn icon(self) -> Icon<'static> {
+        let arrows = ScreenProfile::current().arrows;
+        let (icon, pos) = match self {
+            Arrow::Up => (&bitmaps::UP_ARROW, arrows.up),
+            Arrow::Down => (&bitmaps::DOWN_ARROW, arrows.down),
+            Arrow::Left => (&bitmaps::LEFT_ARROW, arrows.left),
+            Arrow::Right => (&bitmaps::RIGHT_ARROW, arrows.right),
+        };
+        Icon::new(icon, pos)
+    }
+
+    /// Arrow displayed while the matching button is pressed
+    pub fn pressed(self) -> Icon<'static> {
+        match self {
+            Arrow::Up => self.icon().shift_v(-4),
+            Arrow::Down => self.icon().shift_v(4),
+            Arrow::Left => self.icon().shift_h(4),
+            Arrow::Right => self.icon().shift_h(-4),
+        }
+    }
+}
 
 pub const OUTER_PADDING: usize = 2;
+#[deprecated(note = "use `ScreenProfile::current().width`")]
 pub const SCREENW: i16 = (crate::SCREEN_WIDTH - OUTER_PADDING) as i16;
 
+// Arrows at their horizontal position on the compilation target.
+// Gadgets use `Arrow`, which follows the screen profile.
+const RIGHT_X: i16 = (crate::SCREEN_WIDTH - OUTER_PADDING) as i16;
+#[deprecated(note = "use `Arrow::Down.icon()`")]
 pub const DOWN_ARROW: Icon =
-    Icon::from(&bitmaps::DOWN_ARROW).set_x(SCREENW - bitmaps::DOWN_ARROW.width as i16);
+    Icon::from(&bitmaps::DOWN_ARROW).set_x(RIGHT_X - bitmaps::DOWN_ARROW.width as i16);
+#[deprecated(note = "use `Arrow::Left.icon()`")]
 pub const LEFT_ARROW: Icon = Icon::from(&bitmaps::LEFT_ARROW).set_x(OUTER_PADDING as i16);
+#[deprecated(note = "use `Arrow::Right.icon()`")]
 pub const RIGHT_ARROW: Icon =
-    Icon::from(&bitmaps::RIGHT_ARROW).set_x(SCREENW - bitmaps::RIGHT_ARROW.width as i16);
+    Icon::from(&bitmaps::RIGHT_ARROW).set_x(RIGHT_X - bitmaps::RIGHT_ARROW.width as i16);
+#[deprecated(note = "use `Arrow::Up.icon()`")]
 pub const UP_ARROW: Icon = Icon::from(&bitmaps::UP_ARROW).set_x(OUTER_PADDING as i16);
-pub const DOWN_S_ARROW: Icon = DOWN_ARROW.shift_v(4);
-pub const LEFT_S_ARROW: Icon = LEFT_ARROW.shift_h(4);
-pub const RIGHT_S_ARROW: Icon = RIGHT_ARROW.shift_h(-4);
-pub const UP_S_ARROW: Icon = UP_ARROW.shift_v(-4);
+#[deprecated(note = "use `Arrow::Down.pressed()`")]
+pub const DOWN_S_ARROW: Icon = Icon::from(&bitmaps::DOWN_ARROW)
+    .set_x(RIGHT_X - bitmaps::DOWN_ARROW.width as i16)
+    .shift_v(4);
+#[deprecated(note = "use `Arrow::Left.pressed()`")]
+pub const LEFT_S_ARROW: Icon = Icon::from(&bitmaps::LEFT_ARROW).set_x(OUTER_PADDING as i16 + 4);
+#[deprecated(note = "use `Arrow::Right.pressed()`")]
+pub const RIGHT_S_ARROW: Icon =
+    Icon::from(&bitmaps::RIGHT_ARROW).set_x(RIGHT_X - bitmaps::RIGHT_ARROW.width as i16 - 4);
+#[deprecated(note = "use `Arrow::Up.pressed()`")]
+pub const UP_S_ARROW: Icon = Icon::from(&bitmaps::UP_ARROW)
+    .set_x(OUTER_PADDING as i16)
+    .shift_v(-4);
 
 pub const CHECKMARK_ICON: Icon = Icon::from(&bitmaps::CHECKMARK);
 pub const CROSS_ICON: Icon = Icon::from(&bitmaps::CROSS);
@@ -124,3 +240,24 @@ pub const EYE_ICON: Icon = Icon::from(&bitmaps::EYE);
 pub const PROCESSING_ICON: Icon = Icon::from(&bitmaps::PROCESSING);
 pub const VALIDATE_14_ICON: Icon = Icon::from(&bitmaps::VALIDATE_14);
 pub const WARNING_ICON: Icon = Icon::from(&bitmaps::WARNING);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screen::{with_device, Device};
+
+    #[test]
+    fn icons_centered_on_every_device() {
+        for device in [Device::NanoS, Device::NanoX, Device::NanoSPlus] {
+            with_device(device, || {
+                let icon: Icon = (&bitmaps::CHECKMARK).into();
+                let area = CHECKMARK_ICON.bounds();
+                assert_eq!(icon.bounds(), area);
+                let below = device.profile().height - area.y as usize - area.height;
+                assert!(below.abs_diff(area.y as usize) <= 1);
+                assert_eq!(CHECKMARK_ICON.shift_v(3).bounds().y, area.y + 3);
+                assert_eq!(CHECKMARK_ICON.set_y(3).bounds().y, 3);
+            });
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use super::Icon;
-use crate::layout::{Draw, Layout, Location};
+use crate::layout::{centered_box, Align, Area, Draw, Element, Layout, Location};
+use crate::screen::{ScreenProfile, TARGET};
 use ledger_sdk_sys;
-use ledger_sdk_sys::seph::SephTags;
 
 #[repr(u8)]
 pub enum BaglTypes {
@@ -36,29 +36,42 @@ pub struct BaglComponent {
 
 impl BaglComponent {
     pub fn paint(&self) {
+        self.send(&[]);
+    }
+
+    /// Send the component to the MCU, followed by `data`
+    fn send(&self, data: &[&[u8]]) {
         let bagl_comp = unsafe {
             core::slice::from_raw_parts(
                 self as *const BaglComponent as *const u8,
                 core::mem::size_of::<BaglComponent>(),
             )
         };
-
+        let len = data.iter().fold(bagl_comp.len(), |acc, d| acc + d.len());
+        let lenbytes = (len as u16).to_be_bytes();
         ledger_sdk_sys::seph::seph_send(&[
-            SephTags::ScreenDisplayStatus as u8,
-            0,
-            bagl_comp.len() as u8,
+            ledger_sdk_sys::seph::SephTags::ScreenDisplayStatus as u8,
+            lenbytes[0],
+            lenbytes[1],
         ]);
         ledger_sdk_sys::seph::seph_send(bagl_comp);
+        for d in data {
+            ledger_sdk_sys::seph::seph_send(d);
+        }
+    }
+}
+
+fn wait_for_status() {
+    if ledger_sdk_sys::seph::is_status_sent() {
+        // TODO: this does not seem like the right way to fix the problem...
+        let mut spi_buffer = [0u8; 16];
+        ledger_sdk_sys::seph::seph_recv(&mut spi_buffer, 0);
     }
 }
 
 pub trait SendToDisplay {
     fn wait_for_status(&self) {
-        if ledger_sdk_sys::seph::is_status_sent() {
-            // TODO: this does not seem like the right way to fix the problem...
-            let mut spi_buffer = [0u8; 16];
-            ledger_sdk_sys::seph::seph_recv(&mut spi_buffer, 0);
-        }
+        wait_for_status();
     }
     fn paint(&self);
     fn send_to_display(&self) {
@@ -105,8 +118,9 @@ impl Draw for Icon<'_> {
     }
     fn erase(&self) {
         let icon = ledger_sdk_sys::pic_rs(self.icon);
+        let (x, y) = self.origin();
         Rect::new()
-            .pos(self.pos.0, self.pos.1)
+            .pos(x, y)
             .dims(icon.width as u16, icon.height as u16)
             .colors(0, 0xffffff)
             .fill(true)
@@ -139,7 +153,13 @@ pub struct Label<'a> {
     pub layout: Layout,
     pub dims: (u16, u16),
     pub bold: bool,
+    /// Drawn with the large font, which has no bold variant
+    pub large: bool,
     pub text: &'a str,
+    /// Space kept to the screen edge when left or right aligned
+    pub margin: usize,
+    /// Signed shift applied after layout and location
+    pub offset: (i16, i16),
 }
 
 impl<'a> Label<'a> {
@@ -147,9 +167,12 @@ impl<'a> Label<'a> {
         Label {
             loc: Location::Middle,
             layout: Layout::Centered,
-            dims: (128, 11),
+            dims: (TARGET.width as u16, TARGET.line_height as u16),
             bold: false,
+            large: false,
             text: "",
+            margin: crate::PADDING,
+            offset: (0, 0),
         }
     }
 
@@ -157,9 +180,12 @@ impl<'a> Label<'a> {
         Label {
             loc: Location::Middle,
             layout: Layout::Centered,
-            dims: (128, 11),
+            dims: (TARGET.width as u16, TARGET.line_height as u16),
             bold: false,
+            large: false,
             text,
+            margin: crate::PADDING,
+            offset: (0, 0),
         }
     }
 
@@ -178,9 +204,70 @@ impl<'a> Label<'a> {
     pub const fn bold(self) -> Self {
         Label { bold: true, ..self }
     }
+    /// Draw the label with the large font of the screen profile
+    pub const fn large(self) -> Self {
+        Label {
+            large: true,
+            dims: (self.dims.0, 16),
+            ..self
+        }
+    }
     pub fn text(self, text: &'a str) -> Self {
         Label { text, ..self }
     }
+    pub const fn margin(self, margin: usize) -> Self {
+        Label { margin, ..self }
+    }
+    /// Shift the label by `dx` pixels to the right
+    /// and `dy` pixels to the bottom
+    pub const fn offset(self, dx: i16, dy: i16) -> Self {
+        Label {
+            offset: (dx, dy),
+            ..self
+        }
+    }
+
+    /// Font used to measure the label.
+    /// The large font only approximates the widths of `OpenSansLight16px`.
+    pub(crate) fn font(&self) -> &'static crate::fonts::Font {
+        let profile = ScreenProfile::current();
+        match self.large {
+            true => profile.large_font(),
+            false => profile.text_font(self.bold),
+        }
+    }
+
+    fn font_id(&self) -> Font {
+        match (self.large, self.bold) {
+            (true, _) => Font::OpenSansLight16px,
+            (false, true) => Font::OpenSansExtrabold11px,
+            (false, false) => Font::OpenSansRegular11px,
+        }
+    }
+
+    /// Position and width of the box the text is centered in
+    fn text_box(&self) -> (i16, i16, u16) {
+        let (x, width) = match self.layout {
+            // Narrow the box rather than moving it off screen,
+            // so that the text center is shifted by `offset.0`
+            Layout::Centered => centered_box(ScreenProfile::current().width, self.offset.0),
+            _ => {
+                let width = self.font().text_width(self.text);
+                let x = self.layout.get_x_margin(width, self.margin) as i16;
+                (x + self.offset.0, width)
+            }
+        };
+        let y = self.loc.get_y(self.dims.1 as usize) as i16 + self.offset.1;
+        (x, y, width as u16)
+    }
+
+    /// Rectangle touched by `display`
+    pub fn bounds(&self) -> Area {
+        let (x, y, box_width) = self.text_box();
+        let width = self.font().text_width(self.text);
+        let x = x as i32 + Align::Center.offset(width, box_width as usize) as i32;
+        Area::new(x, y as i32, width, self.dims.1 as usize)
+    }
 }
 
 impl<'a> From<&'a str> for Label<'a> {
@@ -194,17 +281,32 @@ impl<'a> Draw for Label<'a> {
         self.paint();
     }
     fn erase(&self) {
-        let x = self.layout.get_x(self.dims.0 as usize) as i16;
-        let y = self.loc.get_y(self.dims.1 as usize) as i16;
+        let area = self.bounds();
+        if area.width == 0 {
+            return;
+        }
         Rect::new()
-            .pos(x, y)
-            .dims(self.dims.0, self.dims.1)
+            .pos(area.x as i16, area.y as i16)
+            .dims(area.width as u16, area.height as u16)
             .colors(0, 0xffffff)
             .fill(true)
             .paint();
     }
 }
 
+impl Element for Label<'_> {
+    fn size(&self) -> (usize, usize) {
+        let font = self.font();
+        (font.text_width(self.text), font.height as usize)
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        let (width, height) = self.size();
+        let dims = (width as u16, height as u16);
+        paint_with(self.text, (x as i16, y as i16), dims, self.font_id());
+    }
+}
+
 pub struct Rect {
     pub pos: (i16, i16),
     pub dims: (u16, u16),
@@ -247,6 +349,16 @@ impl Rect {
     pub const fn userid(self, id: u8) -> Rect {
         Rect { userid: id, ..self }
     }
+
+    /// Rectangle touched by `display`
+    pub const fn bounds(&self) -> Area {
+        Area::new(
+            self.pos.0 as i32,
+            self.pos.1 as i32,
+            self.dims.0 as usize,
+            self.dims.1 as usize,
+        )
+    }
 }
 
 impl Draw for Rect {
@@ -263,6 +375,22 @@ impl Draw for Rect {
     }
 }
 
+impl Element for Rect {
+    fn size(&self) -> (usize, usize) {
+        (self.dims.0 as usize, self.dims.1 as usize)
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        Rect::new()
+            .pos(x as i16, y as i16)
+            .dims(self.dims.0, self.dims.1)
+            .colors(self.colors.0, self.colors.1)
+            .fill(self.fill)
+            .userid(self.userid)
+            .display();
+    }
+}
+
 impl Draw for RectFull {
     fn display(&self) {
         self.paint();
@@ -281,11 +409,12 @@ impl SendToDisplay for Icon<'_> {
     fn paint(&self) {
         self.wait_for_status();
         let icon = ledger_sdk_sys::pic_rs(self.icon);
+        let (x, y) = self.origin();
         let baglcomp = BaglComponent {
             type_: BaglTypes::Icon as u8,
             userid: 0,
-            x: self.pos.0,
-            y: self.pos.1,
+            x,
+            y,
             width: icon.width as u16,
             height: icon.height as u16,
             stroke: 0,
@@ -296,28 +425,14 @@ impl SendToDisplay for Icon<'_> {
             font_id: 0,
             icon_id: 0,
         };
-        let bagl_comp = unsafe {
-            core::slice::from_raw_parts(
-                &baglcomp as *const BaglComponent as *const u8,
-                core::mem::size_of::<BaglComponent>(),
-            )
-        };
-        let lenbytes = ((bagl_comp.len() + 1 + (2 * 4) + icon.bitmap.len()) as u16).to_be_bytes();
-        ledger_sdk_sys::seph::seph_send(&[
-            SephTags::ScreenDisplayStatus as u8,
-            lenbytes[0],
-            lenbytes[1],
-        ]);
-        ledger_sdk_sys::seph::seph_send(bagl_comp);
-        // bpp (1), 'color_index' (2*4)
-        ledger_sdk_sys::seph::seph_send(&[1, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0]);
         let bmp = unsafe {
             core::slice::from_raw_parts(
                 ledger_sdk_sys::pic(icon.bitmap.as_ptr() as *mut c_void) as *const u8,
                 icon.bitmap.len(),
             )
         };
-        ledger_sdk_sys::seph::seph_send(bmp);
+        // bpp (1), 'color_index' (2*4)
+        baglcomp.send(&[&[1, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0], bmp]);
     }
 }
 
@@ -371,56 +486,45 @@ use core::ffi::c_void;
 
 impl<'a> SendToDisplay for Label<'a> {
     fn paint(&self) {
-        self.wait_for_status();
-        let font_id = if self.bold {
-            Font::OpenSansExtrabold11px
-        } else {
-            Font::OpenSansRegular11px
-        };
-        let x = match self.layout {
-            Layout::RightAligned => self.layout.get_x(self.text.len() * 7),
-            _ => 0,
-        };
-        let y = self.loc.get_y(self.dims.1 as usize) as i16;
-        let width = match self.layout {
-            Layout::Centered => crate::SCREEN_WIDTH,
-            _ => self.text.len() * 6,
-        };
-        let baglcomp = BaglComponent {
-            type_: BaglTypes::LabelLine as u8,
-            userid: 0, // FIXME
-            x: x as i16,
-            y: y - 1 + self.dims.1 as i16,
-            width: width as u16, //self.dims.0,
-            height: self.dims.1,
-            stroke: 0,
-            radius: 0,
-            fill: 0,
-            fgcolor: 0xffffffu32,
-            bgcolor: 0,
-            font_id: font_id as u16 | BAGL_FONT_ALIGNMENT_CENTER as u16,
-            icon_id: 0,
-        };
+        let (x, y, width) = self.text_box();
+        paint_with(self.text, (x, y), (width, self.dims.1), self.font_id());
+    }
+}
 
-        let bagl_comp = unsafe {
-            core::slice::from_raw_parts(
-                &baglcomp as *const BaglComponent as *const u8,
-                core::mem::size_of::<BaglComponent>(),
-            )
-        };
-        let lenbytes = ((bagl_comp.len() + self.text.len()) as u16).to_be_bytes();
-        ledger_sdk_sys::seph::seph_send(&[
-            SephTags::ScreenDisplayStatus as u8,
-            lenbytes[0],
-            lenbytes[1],
-        ]);
-        ledger_sdk_sys::seph::seph_send(bagl_comp);
+/// Paint `text` centered in the box of `width` x `height` pixels
+/// whose top-left corner is at `(x, y)`
+pub fn paint_text(text: &str, x: i16, y: i16, width: u16, height: u16, bold: bool) {
+    let font_id = if bold {
+        Font::OpenSansExtrabold11px
+    } else {
+        Font::OpenSansRegular11px
+    };
+    paint_with(text, (x, y), (width, height), font_id);
+}
 
-        unsafe {
-            let pic_text = ledger_sdk_sys::pic(self.text.as_ptr() as *mut u8 as *mut c_void);
-            ledger_sdk_sys::io_seph_send(pic_text as *mut u8, self.text.len() as u16);
-        }
-    }
+fn paint_with(text: &str, (x, y): (i16, i16), (width, height): (u16, u16), font_id: Font) {
+    wait_for_status();
+    let baglcomp = BaglComponent {
+        type_: BaglTypes::LabelLine as u8,
+        userid: 0, // FIXME
+        x,
+        y: y - 1 + height as i16,
+        width,
+        height,
+        stroke: 0,
+        radius: 0,
+        fill: 0,
+        fgcolor: 0xffffffu32,
+        bgcolor: 0,
+        font_id: font_id as u16 | BAGL_FONT_ALIGNMENT_CENTER as u16,
+        icon_id: 0,
+    };
+
+    let text = unsafe {
+        let pic_text = ledger_sdk_sys::pic(text.as_ptr() as *mut u8 as *mut c_void);
+        core::slice::from_raw_parts(pic_text as *const u8, text.len())
+    };
+    baglcomp.send(&[text]);
 }
 
 /// Some common constant Bagls
@@ -1,13 +1,20 @@
 use super::Icon;
-use crate::fonts::OPEN_SANS;
+use crate::fonts::Font;
 use crate::layout::*;
+use crate::screen::ScreenProfile;
 use ledger_sdk_sys;
 
 pub struct Label<'a> {
     pub text: &'a str,
     pub bold: bool,
+    /// Drawn with the large font, which has no bold variant
+    pub large: bool,
     pub loc: Location,
     layout: Layout,
+    /// Space kept to the screen edge when left or right aligned
+    pub margin: usize,
+    /// Signed shift applied after layout and location
+    pub offset: (i16, i16),
 }
 
 impl<'a> From<&'a str> for Label<'a> {
@@ -15,8 +22,11 @@ impl<'a> From<&'a str> for Label<'a> {
         Label {
             text: s,
             bold: false,
+            large: false,
             loc: Location::Middle,
             layout: Layout::Centered,
+            margin: crate::PADDING,
+            offset: (0, 0),
         }
     }
 }
@@ -26,8 +36,11 @@ impl<'a> Label<'a> {
         Label {
             text: s,
             bold: false,
+            large: false,
             loc: Location::Middle,
             layout: Layout::Centered,
+            margin: crate::PADDING,
+            offset: (0, 0),
         }
     }
 
@@ -45,23 +58,67 @@ impl<'a> Label<'a> {
             ..*self
         }
     }
+
+    /// Draw the label with the large font of the screen profile
+    pub const fn large(self) -> Label<'a> {
+        Label {
+            large: true,
+            ..self
+        }
+    }
+
+    pub const fn margin(self, margin: usize) -> Label<'a> {
+        Label { margin, ..self }
+    }
+
+    /// Shift the label by `dx` pixels to the right
+    /// and `dy` pixels to the bottom
+    pub const fn offset(self, dx: i16, dy: i16) -> Label<'a> {
+        Label {
+            offset: (dx, dy),
+            ..self
+        }
+    }
+
+    /// Font of the label, drawn in bold or not
+    pub(crate) fn font(&self, bold: bool) -> &'static Font {
+        let profile = ScreenProfile::current();
+        match self.large {
+            true => profile.large_font(),
+            false => profile.text_font(bold),
+        }
+    }
+
+    /// Top-left corner of the label once placed with `loc` and `layout`
+    pub(crate) fn origin(&self, loc: Location, layout: Layout, bold: bool) -> (i32, i32) {
+        let font = self.font(bold);
+        let width = font.text_width(self.text);
+        let height = font.height as usize;
+        let x = layout.get_x_margin(width, self.margin) as i32 + self.offset.0 as i32;
+        let y = loc.get_y(height) as i32 + self.offset.1 as i32;
+        (x, y)
+    }
+
+    /// Rectangle touched by `display`
+    pub fn bounds(&self) -> Area {
+        let (x, y) = self.origin(self.loc, self.layout, self.bold);
+        let (width, height) = self.size();
+        Area::new(x, y, width, height)
+    }
 }
 
 impl Draw for Label<'_> {
     fn display(&self) {
-        self.text.place(self.loc, self.layout, self.bold);
+        StringPlace::place(self, self.loc, self.layout, self.bold);
     }
     fn erase(&self) {
-        let total_width = self.text.compute_width(self.bold);
-        let c_height = OPEN_SANS[self.bold as usize].height as usize;
-        if total_width != 0 {
-            let x = self.layout.get_x(total_width);
-            let y = self.loc.get_y(c_height);
+        let area = self.bounds();
+        if area.width != 0 {
             pic_draw(
-                x as i32,
-                y as i32,
-                total_width as u32,
-                c_height as u32,
+                area.x,
+                area.y,
+                area.width as u32,
+                area.height as u32,
                 false,
                 &crate::bitmaps::BLANK,
             )
@@ -69,62 +126,47 @@ impl Draw for Label<'_> {
     }
 }
 
+impl Element for Label<'_> {
+    fn size(&self) -> (usize, usize) {
+        let font = self.font(self.bold);
+        (font.text_width(self.text), font.height as usize)
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        crate::string_se::draw_text(self.text, x, y, self.font(self.bold));
+    }
+}
+
 use crate::bagls::RectFull;
 
 impl Draw for RectFull {
     fn display(&self) {
-        unsafe { 
-            ledger_sdk_sys::bagl_hal_draw_rect(
-                1,
-                self.pos.0,
-                self.pos.1,
-                self.width,
-                self.height,
-            );
-        }
+        crate::screen_util::draw_rect(1, self.pos.0, self.pos.1, self.width, self.height);
     }
 
     fn erase(&self) {
-        unsafe {
-            ledger_sdk_sys::bagl_hal_draw_rect(
-                0,
-                self.pos.0,
-                self.pos.1,
-                self.width,
-                self.height,
-            );
-        }
+        crate::screen_util::draw_rect(0, self.pos.0, self.pos.1, self.width, self.height);
     }
 }
 
-
 use core::ffi::c_void;
 
 #[inline(never)]
 fn pic_draw(x: i32, y: i32, width: u32, height: u32, inverted: bool, bitmap: &[u8]) {
-    let inverted = [inverted as u32, !inverted as u32];
-    unsafe {
+    let bitmap = unsafe {
         let pic_bmp = ledger_sdk_sys::pic(bitmap.as_ptr() as *mut c_void);
-        ledger_sdk_sys::bagl_hal_draw_bitmap_within_rect(
-            x,
-            y,
-            width,
-            height,
-            2,
-            inverted.as_ptr(),
-            1,
-            pic_bmp as *const u8,
-            width * height,
-        )
-    }
+        core::slice::from_raw_parts(pic_bmp as *const u8, bitmap.len())
+    };
+    crate::screen_util::draw(x, y, width, height, inverted, bitmap);
 }
 
 impl<'a> Draw for Icon<'a> {
     fn display(&self) {
         let icon = ledger_sdk_sys::pic_rs(self.icon);
+        let (x, y) = self.origin();
         pic_draw(
-            self.pos.0 as i32,
-            self.pos.1 as i32,
+            x as i32,
+            y as i32,
             icon.width,
             icon.height,
             icon.inverted,
@@ -134,9 +176,10 @@ impl<'a> Draw for Icon<'a> {
 
     fn erase(&self) {
         let icon = ledger_sdk_sys::pic_rs(self.icon);
+        let (x, y) = self.origin();
         pic_draw(
-            self.pos.0 as i32,
-            self.pos.1 as i32,
+            x as i32,
+            y as i32,
             icon.width,
             icon.height,
             icon.inverted,
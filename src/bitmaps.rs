@@ -1,3 +1,4 @@
+use crate::layout::Area;
 use crate::screen_util::draw;
 use ledger_sdk_sys;
 
@@ -31,26 +32,20 @@ impl<'a> Glyph<'a> {
             ..self
         }
     }
+    /// Rectangle touched by `draw(x, y)`
+    pub fn bounds(&self, x: i32, y: i32) -> Area {
+        let glyph = ledger_sdk_sys::pic_rs(self);
+        Area::new(x, y, glyph.width as usize, glyph.height as usize)
+    }
     pub fn draw(&self, x: i32, y: i32) {
         draw(x, y, self.width, self.height, self.inverted, &self.bitmap);
     }
 }
 
 pub fn manual_screen_clear() {
-    let inverted = [0u32, 1u32];
-    unsafe {
-        ledger_sdk_sys::bagl_hal_draw_bitmap_within_rect(
-            0, 
-            0, 
-            128, 
-            64, 
-            2, 
-            inverted.as_ptr(), 
-            1, 
-            BLANK.as_ptr(), 
-            128 * 64
-        );
-    }
+    let profile = crate::screen::ScreenProfile::current();
+    let (width, height) = (profile.width as u32, profile.height as u32);
+    draw(0, 0, width, height, false, &BLANK);
 }
 
 use include_gif::include_gif;
@@ -0,0 +1,510 @@
+use core::sync::atomic::{compiler_fence, Ordering};
+
+use crate::bagls::{Arrow, Icon, Label, OUTER_PADDING};
+use crate::bitmaps;
+use crate::event::{AutoRepeat, Button, Event, EventSource, TimedOut};
+use crate::executor::Events;
+use crate::layout::{Area, Draw};
+use crate::screen::ScreenProfile;
+use crate::stack::{Child, HStack, VStack};
+use crate::ui::{clear_screen, Gadget, GadgetExt, SephEvents};
+
+/// Sets of characters of the `TextEntry` wheel
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Category {
+    Lowercase,
+    Uppercase,
+    Digits,
+    Symbols,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [
+        Category::Lowercase,
+        Category::Uppercase,
+        Category::Digits,
+        Category::Symbols,
+    ];
+
+    pub fn chars(self) -> &'static [u8] {
+        match self {
+            Category::Lowercase => b"abcdefghijklmnopqrstuvwxyz",
+            Category::Uppercase => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            Category::Digits => b"0123456789",
+            Category::Symbols => b" !\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~",
+        }
+    }
+
+    /// Name of the wheel entry switching to this category
+    fn name(self) -> &'static str {
+        match self {
+            Category::Lowercase => "abc",
+            Category::Uppercase => "ABC",
+            Category::Digits => "123",
+            Category::Symbols => "#+=",
+        }
+    }
+}
+
+/// Entry of the wheel
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Choice {
+    Char(u8),
+    /// Index in `TextEntry::categories`
+    Category(usize),
+    /// Show the masked text until the next button press
+    Reveal,
+    Delete,
+    Done,
+}
+
+/// Displayed instead of masked characters
+const DOTS: &str = "********************************";
+
+/// Text entry on a wheel: left and right cycle through the
+/// characters of the current category followed by the other
+/// categories, "Delete" and "Done". Both buttons select.
+/// The text is written into the caller's buffer.
+pub struct TextEntry<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+    title: &'a str,
+    categories: &'a [Category],
+    category: usize,
+    position: usize,
+    repeat: AutoRepeat,
+    /// Set once the held button already turned the wheel,
+    /// so that its release does not turn it again
+    held: bool,
+    masked: bool,
+    revealed: bool,
+}
+
+impl<'a> TextEntry<'a> {
+    /// Up to `buf.len()` characters can be entered
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        TextEntry {
+            buf,
+            len: 0,
+            title: "",
+            categories: &Category::ALL,
+            category: 0,
+            position: 0,
+            repeat: AutoRepeat::new(),
+            held: false,
+            masked: false,
+            revealed: false,
+        }
+    }
+
+    /// Text displayed while nothing is entered
+    pub const fn title(self, title: &'a str) -> Self {
+        TextEntry { title, ..self }
+    }
+
+    /// Categories offered, the first one being selected first
+    pub const fn categories(self, categories: &'a [Category]) -> Self {
+        TextEntry { categories, ..self }
+    }
+
+    pub const fn auto_repeat(self, repeat: AutoRepeat) -> Self {
+        TextEntry { repeat, ..self }
+    }
+
+    /// Display the text as dots, and add a "Reveal" entry to the wheel
+    pub const fn masked(self, masked: bool) -> Self {
+        TextEntry { masked, ..self }
+    }
+
+    /// Returns the number of characters written into the buffer
+    pub fn show(self) -> usize {
+        let len = self.len;
+        self.show_with(&mut SephEvents::new()).unwrap_or(len)
+    }
+
+    /// Text entered so far
+    pub fn text(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+
+    fn chars(&self) -> &'static [u8] {
+        self.categories
+            .get(self.category)
+            .map_or(&[], |category| category.chars())
+    }
+
+    fn wheel_len(&self) -> usize {
+        self.chars().len() + self.categories.len().saturating_sub(1) + 2 + self.masked as usize
+    }
+
+    fn choice(&self, position: usize) -> Choice {
+        let chars = self.chars();
+        if let Some(&c) = chars.get(position) {
+            return Choice::Char(c);
+        }
+        let switch = position - chars.len();
+        let others = self.categories.len().saturating_sub(1);
+        if switch < others {
+            // Skip the current category
+            let index = if switch < self.category {
+                switch
+            } else {
+                switch + 1
+            };
+            return Choice::Category(index);
+        }
+        match (switch - others, self.masked) {
+            (0, true) => Choice::Reveal,
+            (0, false) | (1, true) => Choice::Delete,
+            _ => Choice::Done,
+        }
+    }
+
+    fn turn(&mut self, button: Button) {
+        let len = self.wheel_len();
+        self.position = match button {
+            Button::Left => (self.position + len - 1) % len,
+            Button::Right => (self.position + 1) % len,
+            Button::Both => self.position,
+        };
+    }
+
+    /// Last characters of the text, as many as fit on screen
+    fn visible_text(&self) -> &str {
+        let text = if self.masked && !self.revealed {
+            &DOTS[..self.len.min(DOTS.len())]
+        } else {
+            self.text()
+        };
+        let room = ScreenProfile::current().width - 2 * OUTER_PADDING;
+        let font = ScreenProfile::current().text_font(false);
+        let mut start = 0;
+        while font.text_width(&text[start..]) > room {
+            start += 1;
+        }
+        &text[start..]
+    }
+}
+
+impl Gadget for TextEntry<'_> {
+    type Outcome = usize;
+
+    fn render(&self) {
+        clear_screen();
+        let top = if self.len == 0 {
+            self.title
+        } else {
+            self.visible_text()
+        };
+        let choice = self.choice(self.position);
+        let c = [match choice {
+            Choice::Char(c) => c,
+            _ => 0,
+        }];
+        let choice = match choice {
+            Choice::Char(b' ') => "Space",
+            Choice::Char(_) => core::str::from_utf8(&c).unwrap_or(""),
+            Choice::Category(index) => self.categories[index].name(),
+            Choice::Reveal => "Reveal",
+            Choice::Delete => "Delete",
+            Choice::Done => "Done",
+        };
+        let labels = [Label::from(top), Label::from(choice).bold()];
+        let eye = Icon::from(&bitmaps::EYE);
+        let reveal = [Child::Icon(&eye), Child::Label(&labels[1])];
+        let reveal = HStack::new(&reveal).spacing(crate::PADDING * 2);
+        let lines = match self.choice(self.position) {
+            Choice::Reveal => [Child::Label(&labels[0]), Child::HStack(&reveal)],
+            _ => [Child::Label(&labels[0]), Child::Label(&labels[1])],
+        };
+        VStack::new(&lines).place(Area::screen());
+        Arrow::Left.icon().display();
+        Arrow::Right.icon().display();
+        crate::screen_util::screen_update();
+    }
+
+    fn handle(&mut self, event: Event) -> Option<usize> {
+        if let (Event::Press(_), true) = (event, self.revealed) {
+            self.revealed = false;
+            self.render();
+        }
+        match self.repeat.filter(event) {
+            Event::Press(Button::Left) => {
+                self.held = false;
+                Arrow::Left.pressed().instant_display();
+            }
+            Event::Press(Button::Right) => {
+                self.held = false;
+                Arrow::Right.pressed().instant_display();
+            }
+            Event::LongPress(button) | Event::Repeat(button) if button != Button::Both => {
+                self.held = true;
+                self.turn(button);
+                self.render();
+                match button {
+                    Button::Left => Arrow::Left.pressed().instant_display(),
+                    _ => Arrow::Right.pressed().instant_display(),
+                }
+            }
+            Event::Release(Button::Both) => {
+                match self.choice(self.position) {
+                    Choice::Char(c) => {
+                        if self.len < self.buf.len() {
+                            self.buf[self.len] = c;
+                            self.len += 1;
+                        }
+                    }
+                    Choice::Category(index) => {
+                        self.category = index;
+                        self.position = 0;
+                    }
+                    Choice::Reveal => self.revealed = true,
+                    Choice::Delete => {
+                        if self.len > 0 {
+                            self.len -= 1;
+                            self.buf[self.len] = 0;
+                        }
+                    }
+                    Choice::Done => return Some(self.len),
+                }
+                self.render();
+            }
+            Event::Release(button) => {
+                if !self.held {
+                    self.turn(button);
+                }
+                self.render();
+            }
+            _ => (),
+        }
+        None
+    }
+}
+
+/// Masked `TextEntry` owning its buffer,
+/// which is zeroised when dropped
+pub struct SecretEntry<'a, const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    title: &'a str,
+    categories: &'a [Category],
+}
+
+impl<'a, const N: usize> SecretEntry<'a, N> {
+    pub const fn new() -> Self {
+        SecretEntry {
+            buf: [0; N],
+            len: 0,
+            title: "",
+            categories: &Category::ALL,
+        }
+    }
+
+    // Fields are set one by one, as `Drop` forbids `..self`
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = title;
+        self
+    }
+
+    pub fn categories(mut self, categories: &'a [Category]) -> Self {
+        self.categories = categories;
+        self
+    }
+
+    /// Replace the previous text with a new one entered by the user
+    pub fn show(&mut self) -> &str {
+        self.show_with(&mut SephEvents::new()).unwrap_or("")
+    }
+
+    /// Same as `show`, reading events from `source`.
+    /// Returns `None` if `source` runs out of events first.
+    pub fn show_with(&mut self, source: &mut impl EventSource) -> Option<&str> {
+        let len = self.entry().show_with(source);
+        self.entered(len)
+    }
+
+    /// Same as `show`, giving up after `ms` milliseconds
+    /// without any button activity
+    pub fn show_with_timeout(&mut self, ms: u32) -> Result<&str, TimedOut> {
+        let len = self.entry().show_with_timeout(ms).ok();
+        self.entered(len).ok_or(TimedOut)
+    }
+
+    /// Same as `show`, awaiting events from `events`
+    pub async fn show_async(&mut self, events: &Events) -> &str {
+        self.len = self.entry().show_async(events).await;
+        self.text()
+    }
+
+    /// Entry writing over the previous text
+    fn entry(&mut self) -> TextEntry<'_> {
+        self.zeroize();
+        TextEntry::new(&mut self.buf)
+            .title(self.title)
+            .categories(self.categories)
+            .masked(true)
+    }
+
+    /// Keep the text of a completed entry.
+    /// The partial text of an interrupted one is erased.
+    fn entered(&mut self, len: Option<usize>) -> Option<&str> {
+        match len {
+            Some(len) => {
+                self.len = len;
+                Some(self.text())
+            }
+            None => {
+                self.zeroize();
+                None
+            }
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).unwrap_or("")
+    }
+
+    fn zeroize(&mut self) {
+        for byte in self.buf.iter_mut() {
+            // Volatile so that the writes are not optimized out
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for SecretEntry<'_, N> {
+    fn default() -> Self {
+        SecretEntry::new()
+    }
+}
+
+impl<const N: usize> Drop for SecretEntry<'_, N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Script;
+    use crate::screen::{with_device, Device};
+
+    const LEFT: [Event; 2] = [Event::Press(Button::Left), Event::Release(Button::Left)];
+    const RIGHT: [Event; 2] = [Event::Press(Button::Right), Event::Release(Button::Right)];
+    const BOTH: [Event; 2] = [Event::Press(Button::Both), Event::Release(Button::Both)];
+
+    const LETTERS_AND_DIGITS: [Category; 2] = [Category::Lowercase, Category::Digits];
+
+    fn enter(entry: TextEntry, events: &[Event]) -> Option<usize> {
+        with_device(Device::NanoX, || entry.show_with(&mut Script::new(events)))
+    }
+
+    #[test]
+    fn wheel_entries() {
+        let mut buf = [0; 4];
+        let entry = TextEntry::new(&mut buf).categories(&LETTERS_AND_DIGITS);
+        assert_eq!(entry.wheel_len(), 26 + 1 + 2);
+        assert_eq!(entry.choice(0), Choice::Char(b'a'));
+        assert_eq!(entry.choice(25), Choice::Char(b'z'));
+        assert_eq!(entry.choice(26), Choice::Category(1));
+        assert_eq!(entry.choice(27), Choice::Delete);
+        assert_eq!(entry.choice(28), Choice::Done);
+
+        let entry = entry.masked(true);
+        assert_eq!(entry.wheel_len(), 26 + 1 + 3);
+        assert_eq!(entry.choice(27), Choice::Reveal);
+        assert_eq!(entry.choice(28), Choice::Delete);
+        assert_eq!(entry.choice(29), Choice::Done);
+    }
+
+    #[test]
+    fn turn_wraps_around() {
+        let mut buf = [0; 4];
+        let mut entry = TextEntry::new(&mut buf).categories(&LETTERS_AND_DIGITS);
+        entry.turn(Button::Left);
+        assert_eq!(entry.position, entry.wheel_len() - 1);
+        entry.turn(Button::Right);
+        assert_eq!(entry.position, 0);
+        entry.turn(Button::Both);
+        assert_eq!(entry.position, 0);
+    }
+
+    #[test]
+    fn category_switch() {
+        let mut buf = [0; 4];
+        // "123" is right after the letters, then "abc" right after the digits
+        let mut events = [LEFT; 3].concat();
+        events.extend([BOTH, LEFT, LEFT, LEFT, LEFT, BOTH, RIGHT].concat());
+        events.extend([BOTH, RIGHT, BOTH, LEFT, LEFT, BOTH].concat());
+        let entry = TextEntry::new(&mut buf).categories(&LETTERS_AND_DIGITS);
+        assert_eq!(enter(entry, &events), Some(2));
+        assert_eq!(&buf[..2], b"9b");
+    }
+
+    #[test]
+    fn delete_on_empty_text() {
+        let mut buf = [0; 4];
+        let events = [LEFT, LEFT, BOTH, RIGHT, BOTH].concat();
+        assert_eq!(enter(TextEntry::new(&mut buf), &events), Some(0));
+        assert_eq!(buf, [0; 4]);
+    }
+
+    #[test]
+    fn full_buffer() {
+        let mut buf = [0; 2];
+        let events = [BOTH, RIGHT, BOTH, BOTH, LEFT, LEFT, BOTH].concat();
+        assert_eq!(enter(TextEntry::new(&mut buf), &events), Some(2));
+        assert_eq!(&buf, b"ab");
+    }
+
+    #[test]
+    fn reveal_until_next_press() {
+        let mut buf = [0; 4];
+        let mut entry = TextEntry::new(&mut buf).masked(true);
+        // Enter "a", then go to "Reveal" right before "Delete" and "Done"
+        let events = [BOTH, LEFT, LEFT, LEFT, BOTH].concat();
+        with_device(Device::NanoX, || {
+            for event in events {
+                entry.handle(event);
+            }
+            assert!(entry.revealed);
+            assert_eq!(entry.visible_text(), "a");
+            entry.handle(Event::Press(Button::Left));
+            assert!(!entry.revealed);
+            assert_eq!(entry.visible_text(), "*");
+        });
+    }
+
+    #[test]
+    fn secret_zeroized_on_drop() {
+        let mut secret = core::mem::MaybeUninit::new(SecretEntry::<4>::new());
+        let events = [BOTH, RIGHT, BOTH, LEFT, LEFT, BOTH].concat();
+        let text = with_device(Device::NanoX, || unsafe {
+            let entered = secret
+                .assume_init_mut()
+                .show_with(&mut Script::new(&events));
+            entered.map(|text| text.as_bytes().to_vec())
+        });
+        assert_eq!(text.as_deref(), Some(&b"ab"[..]));
+        let buf = unsafe {
+            secret.assume_init_drop();
+            core::ptr::addr_of!((*secret.as_ptr()).buf).read()
+        };
+        assert_eq!(buf, [0; 4]);
+    }
+
+    #[test]
+    fn interrupted_secret_erased() {
+        let mut secret = SecretEntry::<4>::new();
+        let events = [BOTH, RIGHT, BOTH].concat();
+        let completed = with_device(Device::NanoX, || {
+            secret.show_with(&mut Script::new(&events)).is_some()
+        });
+        assert!(!completed);
+        assert_eq!(secret.buf, [0; 4]);
+        assert_eq!(secret.text(), "");
+    }
+}
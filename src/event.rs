@@ -0,0 +1,469 @@
+use core::sync::atomic::{AtomicU32, Ordering};
+use ledger_sdk_sys::buttons::ButtonEvent;
+
+/// SEPH event tags
+const BUTTON_PUSH_EVENT: u8 = 0x05;
+const TICKER_EVENT: u8 = 0x0e;
+const USB_EVENT: u8 = 0x0f;
+const USB_EP_XFER_EVENT: u8 = 0x10;
+const CAPDU_EVENT: u8 = 0x16;
+
+/// Room for a SEPH packet: a 3-byte header followed
+/// by up to a 5-byte command header and 255 bytes of data
+pub const SEPH_PACKET_LEN: usize = 3 + 5 + 255;
+
+/// Default period of ticker events, in milliseconds
+pub const TICKER_INTERVAL_MS: u32 = 100;
+
+/// Ticker period delays are counted with, see `set_ticker_interval`
+static TICKER_MS: AtomicU32 = AtomicU32::new(TICKER_INTERVAL_MS);
+
+/// Start ticker events every `ms` milliseconds.
+/// `AutoRepeat`, `Timeout` and the debounce count time with them.
+#[cfg(not(feature = "speculos"))]
+pub fn setup_ticker(ms: u16) {
+    crate::screen_util::seph_setup_ticker(ms);
+    set_ticker_interval(ms as u32);
+}
+
+/// Tell the crate the period of the ticker, for apps
+/// which call `screen_util::seph_setup_ticker` themselves
+pub fn set_ticker_interval(ms: u32) {
+    TICKER_MS.store(ms, Ordering::Relaxed);
+}
+
+pub fn ticker_interval() -> u32 {
+    TICKER_MS.load(Ordering::Relaxed)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Button {
+    Left,
+    Right,
+    Both,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UsbStatus {
+    Reset,
+    Suspended,
+    Resumed,
+    Other(u8),
+}
+
+impl From<u8> for UsbStatus {
+    fn from(status: u8) -> UsbStatus {
+        match status {
+            0x01 => UsbStatus::Reset,
+            0x04 => UsbStatus::Suspended,
+            0x08 => UsbStatus::Resumed,
+            other => UsbStatus::Other(other),
+        }
+    }
+}
+
+/// Everything that can happen while a gadget is on screen
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    Press(Button),
+    Release(Button),
+    /// Button held for longer than the `AutoRepeat` delay
+    LongPress(Button),
+    /// Button still held after a `LongPress`,
+    /// sent at the `AutoRepeat` rate
+    Repeat(Button),
+    /// Periodic ticker
+    Ticker,
+    /// Complete command APDU received from the host.
+    /// The SEPH packet carrying it is left in the buffer
+    /// given to `ui::get_event`, see `SephEvents::packet`.
+    Apdu,
+    /// USB endpoint transfer, carrying part of an APDU.
+    /// The SEPH packet is left in the same buffer as for `Apdu`,
+    /// for the app IO layer to hand it to the USB stack.
+    UsbTransfer,
+    Usb(UsbStatus),
+    /// Any other SEPH event, with its tag
+    Unknown(u8),
+}
+
+impl From<ButtonEvent> for Event {
+    fn from(event: ButtonEvent) -> Event {
+        match event {
+            ButtonEvent::LeftButtonPress => Event::Press(Button::Left),
+            ButtonEvent::RightButtonPress => Event::Press(Button::Right),
+            ButtonEvent::BothButtonsPress => Event::Press(Button::Both),
+            ButtonEvent::LeftButtonRelease => Event::Release(Button::Left),
+            ButtonEvent::RightButtonRelease => Event::Release(Button::Right),
+            ButtonEvent::BothButtonsRelease => Event::Release(Button::Both),
+        }
+    }
+}
+
+/// Length of the SEPH packet in `packet`, header included,
+/// or of the part that fit in `packet`
+pub fn packet_len(packet: &[u8]) -> usize {
+    match packet {
+        [_, hi, lo, ..] => (3 + u16::from_be_bytes([*hi, *lo]) as usize).min(packet.len()),
+        _ => 0,
+    }
+}
+
+/// Decode a SEPH packet whose header is in `packet`.
+/// Returns `None` for button pushes, which need
+/// the buttons state to be decoded.
+pub(crate) fn decode(packet: &[u8]) -> Option<Event> {
+    let tag = *packet.first()?;
+    match tag {
+        BUTTON_PUSH_EVENT => None,
+        TICKER_EVENT => Some(Event::Ticker),
+        USB_EVENT => Some(Event::Usb(packet.get(3).copied().unwrap_or(0).into())),
+        USB_EP_XFER_EVENT => Some(Event::UsbTransfer),
+        CAPDU_EVENT => Some(Event::Apdu),
+        tag => Some(Event::Unknown(tag)),
+    }
+}
+
+/// Turns ticker events received while a button is held
+/// into a `LongPress` followed by `Repeat` events.
+/// Relies on the ticker being set up, see `setup_ticker`.
+#[derive(Copy, Clone, Debug)]
+pub struct AutoRepeat {
+    delay_ms: u32,
+    rate_ms: u32,
+    held: Option<Button>,
+    held_ms: u32,
+}
+
+impl AutoRepeat {
+    pub const fn new() -> Self {
+        AutoRepeat {
+            delay_ms: 600,
+            rate_ms: 200,
+            held: None,
+            held_ms: 0,
+        }
+    }
+
+    /// Never emit `LongPress` nor `Repeat`
+    pub const fn disabled() -> Self {
+        AutoRepeat::new().delay(u32::MAX)
+    }
+
+    /// Time a button must be held before `LongPress`
+    pub const fn delay(self, delay_ms: u32) -> Self {
+        AutoRepeat { delay_ms, ..self }
+    }
+
+    /// Time between two `Repeat` events
+    pub const fn rate(self, rate_ms: u32) -> Self {
+        AutoRepeat { rate_ms, ..self }
+    }
+
+    /// Pass `event` through, replacing ticker events
+    /// with `LongPress` or `Repeat` when relevant
+    pub fn filter(&mut self, event: Event) -> Event {
+        match event {
+            Event::Press(button) => {
+                self.held = Some(button);
+                self.held_ms = 0;
+            }
+            Event::Release(_) => self.held = None,
+            Event::Ticker => {
+                if let Some(button) = self.held {
+                    let before = self.held_ms;
+                    self.held_ms = before.saturating_add(ticker_interval());
+                    if before < self.delay_ms && self.held_ms >= self.delay_ms {
+                        return Event::LongPress(button);
+                    }
+                    let rate = self.rate_ms.max(1);
+                    if before >= self.delay_ms
+                        && (self.held_ms - self.delay_ms) / rate > (before - self.delay_ms) / rate
+                    {
+                        return Event::Repeat(button);
+                    }
+                }
+            }
+            _ => (),
+        }
+        event
+    }
+}
+
+impl Default for AutoRepeat {
+    fn default() -> Self {
+        AutoRepeat::new()
+    }
+}
+
+/// Returned by the `*_with_timeout` gadget methods
+/// when the user did not interact in time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TimedOut;
+
+/// Counts ticker events since the last button activity.
+/// Does not count while a button is held, e.g. to scroll.
+/// Relies on the ticker being set up, see `setup_ticker`.
+#[derive(Copy, Clone, Debug)]
+pub struct Timeout {
+    limit_ms: Option<u32>,
+    idle_ms: u32,
+    held: bool,
+}
+
+impl Timeout {
+    /// Expire after `ms` milliseconds without any button activity
+    pub const fn new(ms: u32) -> Self {
+        Timeout {
+            limit_ms: Some(ms),
+            idle_ms: 0,
+            held: false,
+        }
+    }
+
+    /// Never expire
+    pub const fn never() -> Self {
+        Timeout {
+            limit_ms: None,
+            idle_ms: 0,
+            held: false,
+        }
+    }
+
+    /// Account for `event` and tell whether the timeout expired
+    pub fn expired(&mut self, event: Event) -> bool {
+        match event {
+            Event::Press(_) => {
+                self.held = true;
+                self.idle_ms = 0;
+            }
+            Event::Release(_) => {
+                self.held = false;
+                self.idle_ms = 0;
+            }
+            Event::Ticker if !self.held => {
+                self.idle_ms = self.idle_ms.saturating_add(ticker_interval())
+            }
+            _ => (),
+        }
+        match self.limit_ms {
+            Some(limit) => self.idle_ms >= limit,
+            None => false,
+        }
+    }
+}
+
+/// Where gadgets read their events from.
+/// `ui::SephEvents` reads them from the SE proxy, `Script`
+/// replays a fixed list, for instance in tests.
+pub trait EventSource {
+    /// Wait for the next event.
+    /// Returns `None` once the source has no more events.
+    fn next_event(&mut self) -> Option<Event>;
+}
+
+/// Replays a predetermined list of events, then runs out.
+/// Gadgets ignore releases whose press is not in the script.
+pub struct Script<'a> {
+    events: &'a [Event],
+    next: usize,
+}
+
+impl<'a> Script<'a> {
+    pub const fn new(events: &'a [Event]) -> Self {
+        Script { events, next: 0 }
+    }
+
+    /// Events not replayed yet
+    pub fn remaining(&self) -> &'a [Event] {
+        self.events.get(self.next..).unwrap_or(&[])
+    }
+}
+
+impl EventSource for Script<'_> {
+    fn next_event(&mut self) -> Option<Event> {
+        let event = self.events.get(self.next).copied()?;
+        self.next += 1;
+        Some(event)
+    }
+}
+
+/// Debounce shared by every gadget, see `set_debounce`
+static DEBOUNCE_MS: AtomicU32 = AtomicU32::new(0);
+
+/// Ignore button presses for `ms` milliseconds after a gadget
+/// is shown. Counted with ticker events, so the actual delay
+/// is rounded up to the ticker interval.
+///
+/// The ticker must be running (see `setup_ticker`). Without it,
+/// no press is dropped.
+pub fn set_debounce(ms: u32) {
+    DEBOUNCE_MS.store(ms, Ordering::Relaxed);
+}
+
+pub fn debounce() -> u32 {
+    DEBOUNCE_MS.load(Ordering::Relaxed)
+}
+
+/// Drops button events left over from the previous screen:
+/// releases whose press happened before the screen was shown,
+/// and presses arriving within the debounce delay. The delay
+/// is counted from the first ticker, presses received before
+/// it are accepted.
+///
+/// Each screen owns its guard. A release is only let through if
+/// its press was accepted by the same guard, so nothing needs to be
+/// known about the previous screen.
+#[derive(Copy, Clone, Debug)]
+pub struct InputGuard {
+    debounce_ms: u32,
+    shown_ms: u32,
+    /// A ticker was received since the screen was shown
+    ticked: bool,
+    /// A press was accepted since the screen was shown
+    armed: bool,
+}
+
+impl InputGuard {
+    /// Guard for a screen shown just now, using the global debounce
+    pub fn new() -> Self {
+        InputGuard {
+            debounce_ms: debounce(),
+            shown_ms: 0,
+            ticked: false,
+            armed: false,
+        }
+    }
+
+    /// Returns `None` if `event` must be ignored
+    pub fn filter(&mut self, event: Event) -> Option<Event> {
+        match event {
+            Event::Ticker => {
+                self.ticked = true;
+                self.shown_ms = self.shown_ms.saturating_add(ticker_interval());
+            }
+            Event::Press(_) if self.ticked && self.shown_ms < self.debounce_ms => return None,
+            Event::Press(_) => self.armed = true,
+            Event::Release(_) if !self.armed => return None,
+            _ => (),
+        }
+        Some(event)
+    }
+}
+
+impl Default for InputGuard {
+    fn default() -> Self {
+        InputGuard::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_packets() {
+        assert_eq!(decode(&[TICKER_EVENT, 0, 1, 0]), Some(Event::Ticker));
+        assert_eq!(
+            decode(&[USB_EVENT, 0, 1, 0x04]),
+            Some(Event::Usb(UsbStatus::Suspended))
+        );
+        assert_eq!(
+            decode(&[USB_EP_XFER_EVENT, 0, 3, 0x82]),
+            Some(Event::UsbTransfer)
+        );
+        assert_eq!(decode(&[CAPDU_EVENT, 0, 5, 0xe0]), Some(Event::Apdu));
+        assert_eq!(decode(&[BUTTON_PUSH_EVENT, 0, 1, 2]), None);
+        assert_eq!(decode(&[]), None);
+    }
+
+    #[test]
+    fn guard_drops_early_presses() {
+        let mut guard = InputGuard {
+            debounce_ms: 200,
+            ..InputGuard::new()
+        };
+        let left = Button::Left;
+        assert_eq!(guard.filter(Event::Release(left)), None);
+        assert_eq!(guard.filter(Event::Ticker), Some(Event::Ticker));
+        assert_eq!(guard.filter(Event::Press(left)), None);
+        assert_eq!(guard.filter(Event::Release(left)), None);
+        assert_eq!(guard.filter(Event::Ticker), Some(Event::Ticker));
+        assert_eq!(guard.filter(Event::Press(left)), Some(Event::Press(left)));
+        assert_eq!(
+            guard.filter(Event::Release(left)),
+            Some(Event::Release(left))
+        );
+    }
+
+    #[test]
+    fn guard_without_ticker() {
+        let mut guard = InputGuard {
+            debounce_ms: 200,
+            ..InputGuard::new()
+        };
+        let left = Button::Left;
+        assert_eq!(guard.filter(Event::Release(left)), None);
+        assert_eq!(guard.filter(Event::Press(left)), Some(Event::Press(left)));
+        assert_eq!(
+            guard.filter(Event::Release(left)),
+            Some(Event::Release(left))
+        );
+    }
+
+    #[test]
+    fn packet_length() {
+        assert_eq!(packet_len(&[CAPDU_EVENT, 0, 2, 0xe0, 0x01, 0xff]), 5);
+        // Truncated to the buffer
+        assert_eq!(packet_len(&[CAPDU_EVENT, 1, 0, 0xe0]), 4);
+        assert_eq!(packet_len(&[CAPDU_EVENT]), 0);
+    }
+
+    #[test]
+    fn auto_repeat_after_delay() {
+        let mut repeat = AutoRepeat::new().delay(300).rate(200);
+        let right = Button::Right;
+        assert_eq!(repeat.filter(Event::Press(right)), Event::Press(right));
+        let ticks: [Event; 7] = core::array::from_fn(|_| repeat.filter(Event::Ticker));
+        let expected = [
+            Event::Ticker,
+            Event::Ticker,
+            Event::LongPress(right),
+            Event::Ticker,
+            Event::Repeat(right),
+            Event::Ticker,
+            Event::Repeat(right),
+        ];
+        assert_eq!(ticks, expected);
+        repeat.filter(Event::Release(right));
+        assert_eq!(repeat.filter(Event::Ticker), Event::Ticker);
+    }
+
+    #[test]
+    fn timeout_reset_by_buttons() {
+        let mut timeout = Timeout::new(250);
+        assert!(!timeout.expired(Event::Ticker));
+        assert!(!timeout.expired(Event::Ticker));
+        assert!(!timeout.expired(Event::Press(Button::Left)));
+        // Not counted while the button is held
+        for _ in 0..5 {
+            assert!(!timeout.expired(Event::Ticker));
+        }
+        assert!(!timeout.expired(Event::Release(Button::Left)));
+        assert!(!timeout.expired(Event::Ticker));
+        assert!(!timeout.expired(Event::Ticker));
+        assert!(timeout.expired(Event::Ticker));
+        assert!(!Timeout::never().expired(Event::Ticker));
+    }
+
+    #[test]
+    fn script_replays_then_runs_out() {
+        let events = [Event::Press(Button::Left), Event::Ticker];
+        let mut script = Script::new(&events);
+        assert_eq!(script.next_event(), Some(Event::Press(Button::Left)));
+        assert_eq!(script.remaining(), &[Event::Ticker]);
+        assert_eq!(script.next_event(), Some(Event::Ticker));
+        assert_eq!(script.next_event(), None);
+        assert!(script.remaining().is_empty());
+    }
+}
@@ -0,0 +1,172 @@
+use core::cell::Cell;
+use core::future::Future;
+use core::mem::ManuallyDrop;
+use core::pin::Pin;
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+use crate::event::{Event, EventSource};
+
+/// Mailbox between an `EventSource` and the futures awaiting events.
+/// `block_on` puts one event in it at a time, and polls the
+/// future until the event is consumed or everything is pending.
+pub struct Events {
+    slot: Cell<Option<Event>>,
+}
+
+impl Events {
+    pub const fn new() -> Self {
+        Events {
+            slot: Cell::new(None),
+        }
+    }
+
+    /// Wait for the next event
+    pub fn next(&self) -> NextEvent<'_> {
+        self.next_where(|_| true)
+    }
+
+    /// Wait for the next event accepted by `accept`.
+    /// Other events are left to the other futures.
+    pub fn next_where(&self, accept: fn(&Event) -> bool) -> NextEvent<'_> {
+        NextEvent {
+            events: self,
+            accept,
+        }
+    }
+
+    /// Run `future` to completion, feeding it events from `source`.
+    /// Events that no future took are dropped.
+    /// Returns `None` if `source` runs out of events first.
+    pub fn block_on<F: Future>(
+        &self,
+        source: &mut impl EventSource,
+        future: F,
+    ) -> Option<F::Output> {
+        let mut future = core::pin::pin!(future);
+        // Dropping a waker calls through its vtable, see `noop_waker`
+        let waker = ManuallyDrop::new(noop_waker());
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return Some(output);
+            }
+            self.slot.set(Some(source.next_event()?));
+        }
+    }
+}
+
+impl Default for Events {
+    fn default() -> Self {
+        Events::new()
+    }
+}
+
+/// Future returned by `Events::next` and `Events::next_where`
+pub struct NextEvent<'a> {
+    events: &'a Events,
+    accept: fn(&Event) -> bool,
+}
+
+impl Future for NextEvent<'_> {
+    type Output = Event;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Event> {
+        match self.events.slot.get() {
+            Some(event) if (self.accept)(&event) => {
+                self.events.slot.set(None);
+                Poll::Ready(event)
+            }
+            _ => Poll::Pending,
+        }
+    }
+}
+
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+/// Wait for the first of `a` and `b` to complete, dropping the other one.
+///
+/// To keep a future alive across several `select`s, e.g. a review
+/// interrupted by APDUs, pin it and pass `future.as_mut()`:
+/// only the reference is dropped, and the future can be selected
+/// again once the other one was handled.
+pub fn select<A: Future, B: Future>(a: A, b: B) -> Select<A, B> {
+    Select { a, b }
+}
+
+/// Future returned by `select`
+pub struct Select<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Future, B: Future> Future for Select<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Neither field is ever moved out of the pinned `Select`
+        let this = unsafe { self.get_unchecked_mut() };
+        let a = unsafe { Pin::new_unchecked(&mut this.a) };
+        if let Poll::Ready(output) = a.poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        let b = unsafe { Pin::new_unchecked(&mut this.b) };
+        if let Poll::Ready(output) = b.poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+        Poll::Pending
+    }
+}
+
+/// `block_on` polls after every event, so nothing needs to be woken.
+/// Its vtable cannot be translated (see `crate::pic`):
+/// the waker must never be cloned, woken nor dropped.
+fn noop_waker() -> Waker {
+    fn clone(_: *const ()) -> RawWaker {
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+    fn noop(_: *const ()) {}
+    static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    unsafe { Waker::from_raw(clone(core::ptr::null())) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{Button, Script};
+
+    fn is_button(event: &Event) -> bool {
+        matches!(event, Event::Press(_) | Event::Release(_))
+    }
+
+    #[test]
+    fn pinned_future_selected_again() {
+        let events = Events::new();
+        let left = Button::Left;
+        let script = [
+            Event::Press(left),
+            Event::Apdu,
+            Event::Apdu,
+            Event::Release(left),
+        ];
+        let mut source = Script::new(&script);
+        let review = async {
+            events.next_where(is_button).await;
+            events.next_where(is_button).await
+        };
+        let mut review = core::pin::pin!(review);
+        let mut commands = 0;
+        let outcome = loop {
+            let apdu = events.next_where(|event| *event == Event::Apdu);
+            match events.block_on(&mut source, select(review.as_mut(), apdu)) {
+                Some(Either::Left(event)) => break event,
+                Some(Either::Right(_)) => commands += 1,
+                None => panic!("script exhausted"),
+            }
+        };
+        assert_eq!(outcome, Event::Release(left));
+        assert_eq!(commands, 2);
+    }
+}
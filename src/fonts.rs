@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+mod dejavu;
 mod opensans;
 
 use opensans::CharArray;
@@ -18,6 +19,22 @@ impl Font {
             height,
         }
     }
+
+    /// Width in pixels of a single character.
+    /// Characters outside of the font range have no width.
+    pub fn char_width(&self, c: u8) -> usize {
+        self.dims
+            .get((c as usize).wrapping_sub(0x20))
+            .map_or(0, |w| *w as usize)
+    }
+
+    /// Width in pixels of `text` once rendered with this font
+    pub fn text_width(&self, text: &str) -> usize {
+        text.as_bytes()
+            .iter()
+            .map(ledger_sdk_sys::pic_rs)
+            .fold(0, |acc, c| acc + self.char_width(*c))
+    }
 }
 
 const OPEN_SANS_REGULAR_11PX: Font = Font::new(
@@ -31,4 +48,11 @@ const OPEN_SANS_EXTRABOLD_11PX: Font = Font::new(
     12,
 );
 
-pub const OPEN_SANS: [Font; 2] = [OPEN_SANS_REGULAR_11PX, OPEN_SANS_EXTRABOLD_11PX];
+pub static OPEN_SANS: [Font; 2] = [OPEN_SANS_REGULAR_11PX, OPEN_SANS_EXTRABOLD_11PX];
+
+/// Font for a single value or word displayed on its own
+pub static LARGE: Font = Font::new(
+    dejavu::DEJAVU_SANS_BOLD_15PX_CHARS,
+    dejavu::DEJAVU_SANS_BOLD_15PX_DIMS,
+    16,
+);
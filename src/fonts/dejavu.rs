@@ -0,0 +1,359 @@
+//! DejaVu Sans Bold, rendered at 15px into 16 pixel high cells.
+//! DejaVu fonts are derived from Bitstream Vera,
+//! see https://dejavu-fonts.github.io/License.html
+
+use super::opensans::CharArray;
+
+pub const DEJAVU_SANS_BOLD_15PX_CHARS: CharArray = CharArray([
+    &[0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[
+        0x00, 0x06, 0x83, 0xC1, 0x60, 0x30, 0x18, 0x00, 0x06, 0x83, 0x01, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x36, 0x36, 0x36, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00,
+    ],
+    &[
+        0x00, 0x00, 0xCC, 0x80, 0x09, 0x90, 0x01, 0x32, 0xF8, 0x1F, 0x4C, 0x80, 0x0C, 0xFE, 0x07,
+        0x13, 0x20, 0x03, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x20, 0x80, 0x80, 0x07, 0x2B, 0x2C, 0xF0, 0xC0, 0x0F, 0x7E, 0xE0, 0x81, 0x46, 0x1A, 0x3E,
+        0x20, 0x80, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x1E, 0x8E, 0x19, 0xC3, 0xCC, 0x60, 0x76, 0x30, 0x1B, 0xF0, 0xF6, 0x80, 0xCF,
+        0xE0, 0x66, 0x30, 0x33, 0x8C, 0x19, 0x87, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x1E, 0x60, 0x04, 0x0C, 0x80, 0x01, 0x78, 0x98, 0x1B, 0x33, 0x26, 0x86, 0xC7,
+        0x60, 0x30, 0x1E, 0x7C, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[0xC0, 0x18, 0x63, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    &[
+        0x00, 0x0C, 0x83, 0x61, 0x30, 0x18, 0x0C, 0x06, 0x83, 0x81, 0xC1, 0xC0, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x03, 0x83, 0x81, 0xC1, 0x60, 0x30, 0x18, 0x0C, 0x86, 0xC1, 0x30, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x08, 0x49, 0x3E, 0x1C, 0x3E, 0x49, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x80, 0x01, 0x30, 0x00, 0x06, 0xC0, 0x80, 0xFF, 0xF1, 0x3F, 0x60, 0x00,
+        0x0C, 0x80, 0x01, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x61, 0x18, 0xC6, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0xE0, 0xFB, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x61, 0x18, 0x00, 0x00, 0x00,
+    ],
+    &[0x00, 0x63, 0xC4, 0x18, 0x21, 0xC6, 0x88, 0x31, 0x00, 0x00],
+    &[
+        0x00, 0xE0, 0xC1, 0x8C, 0x61, 0x86, 0x19, 0x66, 0x98, 0x61, 0x86, 0x19, 0xC6, 0x0C, 0x1E,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF0, 0x60, 0x03, 0x0C, 0x30, 0xC0, 0x00, 0x03, 0x0C, 0x30, 0xC0, 0x00, 0x03, 0x3F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF0, 0x21, 0x0C, 0x60, 0x80, 0x01, 0x07, 0x0C, 0x18, 0x30, 0x60, 0xC0, 0x80, 0x7F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF0, 0x21, 0x1C, 0x60, 0x80, 0x01, 0x87, 0x0F, 0x70, 0x80, 0x01, 0x26, 0x1C, 0x1F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x0F, 0x6C, 0x20, 0x83, 0x18, 0xC2, 0x18, 0x46, 0x30, 0xFE, 0x07, 0x0C, 0x60,
+        0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF8, 0x63, 0x80, 0x01, 0x06, 0xF8, 0x21, 0x0C, 0x60, 0x80, 0x01, 0x26, 0x0C, 0x1F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xE0, 0xC1, 0x08, 0x01, 0x06, 0xF8, 0xE1, 0x8C, 0x61, 0x86, 0x19, 0xC6, 0x0C, 0x1E,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF8, 0x07, 0x18, 0x70, 0xC0, 0x00, 0x03, 0x06, 0x18, 0x30, 0xC0, 0x80, 0x03, 0x06,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF0, 0xE3, 0x9C, 0x61, 0x86, 0x39, 0x87, 0x87, 0x73, 0x86, 0x19, 0xE6, 0x1C, 0x3F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xE0, 0xC1, 0x8C, 0x61, 0x86, 0x19, 0xC6, 0x1C, 0x7E, 0x80, 0x01, 0x42, 0x0C, 0x1E,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x86, 0x61, 0x00, 0x80, 0x61, 0x18, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x86, 0x61, 0x00, 0x80, 0x61, 0x18, 0xC6, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x78, 0xC0, 0x07, 0x1F, 0x60, 0x00, 0x7C, 0x00,
+        0x7C, 0x00, 0x1E, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x7F, 0xF8, 0x0F, 0x00, 0x00, 0x00, 0xFC, 0x87,
+        0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xC0, 0x03, 0xF0, 0x01, 0xF0, 0x01, 0x30, 0xC0, 0x07,
+        0x1F, 0xF0, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF8, 0x08, 0x03, 0x06, 0x0E, 0x0E, 0x0E, 0x0C, 0x00, 0x30, 0x60, 0xC0, 0x00, 0x00,
+        0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0xF0, 0x01, 0x06, 0x83, 0x01, 0x42, 0x7E, 0x93, 0x3B, 0xC9, 0x98, 0x64, 0x4C,
+        0x32, 0x26, 0xB9, 0x0B, 0xF9, 0x83, 0x01, 0x80, 0xC1, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x80, 0x03, 0x1C, 0xB0, 0x81, 0x0D, 0xC6, 0x30, 0x86, 0x31, 0xFE, 0x33, 0x98, 0xC1,
+        0x06, 0x0C, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF0, 0x87, 0xE1, 0x0C, 0x66, 0x30, 0xC3, 0xF9, 0xC7, 0x70, 0x06, 0x33, 0x98, 0xE1,
+        0xFC, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x80, 0x1F, 0x86, 0x18, 0x60, 0x00, 0x03, 0x18, 0xC0, 0x00, 0x06, 0x60, 0x00, 0x86,
+        0xE0, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xE0, 0x0F, 0x86, 0x61, 0x30, 0x06, 0x66, 0x60, 0x06, 0x66, 0x60, 0x06, 0x66, 0x30,
+        0x86, 0xE1, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF8, 0x67, 0x80, 0x01, 0x06, 0x18, 0xE0, 0x9F, 0x01, 0x06, 0x18, 0x60, 0x80, 0x7F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF8, 0x67, 0x80, 0x01, 0x06, 0x18, 0xE0, 0x9F, 0x01, 0x06, 0x18, 0x60, 0x80, 0x01,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x3F, 0x1C, 0xC2, 0x00, 0x06, 0x60, 0x00, 0x86, 0x67, 0x60, 0x06, 0xC6, 0x60,
+        0x18, 0x06, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x60, 0x60, 0x06, 0x66, 0x60, 0x06, 0x66, 0x60, 0xFE, 0x67, 0x60, 0x06, 0x66, 0x60,
+        0x06, 0x66, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xC3, 0x30, 0x0C, 0xC3, 0x30, 0x0C, 0xC3, 0x30, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xC3, 0x30, 0x0C, 0xC3, 0x30, 0x0C, 0xC3, 0x30, 0x0C, 0x73, 0x00,
+    ],
+    &[
+        0x00, 0x30, 0x98, 0x61, 0x8C, 0x61, 0x06, 0x1B, 0x78, 0xC0, 0x06, 0x66, 0x30, 0x86, 0x61,
+        0x0C, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x18, 0x60, 0x80, 0x01, 0x06, 0x18, 0x60, 0x80, 0x01, 0x06, 0x18, 0x60, 0x80, 0x7F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x80, 0x03, 0xE7, 0xE1, 0x79, 0x78, 0x36, 0x9B, 0xCD, 0x66, 0x9E, 0x99, 0x67, 0xC6,
+        0x98, 0x31, 0x66, 0x80, 0x19, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xE0, 0x60, 0x0E, 0xE6, 0x61, 0x36, 0x66, 0x63, 0x66, 0x66, 0x6C, 0xC6, 0x66, 0x78,
+        0x06, 0x67, 0x70, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x3E, 0x70, 0x1C, 0x06, 0x63, 0xC0, 0x0C, 0x98, 0x01, 0x33, 0x60, 0x06, 0x8C,
+        0xC1, 0x70, 0x1C, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF0, 0x87, 0x61, 0x0C, 0x66, 0x30, 0x83, 0x19, 0xC6, 0x1F, 0x06, 0x30, 0x80, 0x01,
+        0x0C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x3E, 0x70, 0x1C, 0x06, 0x63, 0xC0, 0x0C, 0x98, 0x01, 0x33, 0x60, 0x06, 0x8C,
+        0xC1, 0x70, 0x1C, 0xF8, 0x00, 0x38, 0x00, 0x0E, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xF0, 0x87, 0x71, 0x0C, 0x63, 0x18, 0xC3, 0x18, 0xC7, 0x1F, 0xC6, 0x30, 0x8C, 0x61,
+        0x0C, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xC0, 0x0F, 0x41, 0x0C, 0xE0, 0x00, 0x7F, 0xE0, 0x07, 0x70, 0x00, 0x03, 0x98, 0x40,
+        0xFC, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xFC, 0x0F, 0x03, 0x0C, 0x30, 0xC0, 0x00, 0x03, 0x0C, 0x30, 0xC0, 0x00, 0x03, 0x0C,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x60, 0x60, 0x06, 0x66, 0x60, 0x06, 0x66, 0x60, 0x06, 0x66, 0x60, 0x06, 0x66, 0x60,
+        0x0C, 0x83, 0x1F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x30, 0x60, 0x06, 0x63, 0x30, 0x06, 0xC3, 0x18, 0x8C, 0xC1, 0x18, 0xD8, 0x80, 0x0D,
+        0xD8, 0x00, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x0C, 0x87, 0x19, 0x0E, 0x63, 0x1C, 0xC3, 0x38, 0x86, 0xD9, 0x0C, 0xB3, 0x19,
+        0x6C, 0x1B, 0xD8, 0x36, 0xB0, 0x68, 0xC0, 0x71, 0x80, 0xE3, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xC0, 0xC1, 0x31, 0x18, 0x8C, 0x01, 0x1B, 0xE0, 0x03, 0x38, 0x80, 0x0D, 0xB0, 0x01,
+        0x63, 0x30, 0x18, 0x07, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xE0, 0x70, 0x0C, 0x83, 0x19, 0x98, 0x01, 0x0F, 0xF0, 0x00, 0x06, 0x60, 0x00, 0x06,
+        0x60, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0xE0, 0x7F, 0x00, 0x06, 0x30, 0x80, 0x01, 0x0C, 0x60, 0x00, 0x03, 0x18, 0xC0, 0x00,
+        0x06, 0xE0, 0x7F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x8F, 0xC1, 0x60, 0x30, 0x18, 0x0C, 0x06, 0x83, 0xC1, 0x60, 0xF0, 0x00, 0x00,
+    ],
+    &[0x60, 0x0C, 0x61, 0x0C, 0x21, 0x8C, 0x21, 0x8C, 0x01, 0x00],
+    &[
+        0x00, 0x0F, 0x06, 0x83, 0xC1, 0x60, 0x30, 0x18, 0x0C, 0x06, 0x83, 0xF1, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x18, 0x80, 0x07, 0x98, 0x81, 0x61, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0xFF,
+    ],
+    &[
+        0x06, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xF8, 0x10, 0x06, 0x18, 0x7F, 0x86, 0x19, 0x66, 0x1C, 0x6F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x18, 0x60, 0x80, 0x01, 0x76, 0x38, 0x63, 0x98, 0x61, 0x86, 0x19, 0xE6, 0x8C, 0x1D,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x80, 0x87, 0x91, 0x01, 0x03, 0x06, 0x0C, 0x30, 0xC2, 0x03, 0x00,
+        0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x06, 0x18, 0x60, 0xB8, 0x31, 0x67, 0x98, 0x61, 0x86, 0x19, 0xC6, 0x1C, 0x6E,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0x30, 0x63, 0x98, 0x7F, 0x06, 0x18, 0xC0, 0x10, 0x3E,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x1C, 0x83, 0xE1, 0x63, 0x30, 0x18, 0x0C, 0x06, 0x83, 0x01, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xB8, 0x31, 0x67, 0x98, 0x61, 0x86, 0x19, 0xC6, 0x1C, 0x6E,
+        0x80, 0x11, 0x83, 0x07, 0x00,
+    ],
+    &[
+        0x00, 0x18, 0x60, 0x80, 0x01, 0xF6, 0x38, 0x66, 0x98, 0x61, 0x86, 0x19, 0x66, 0x98, 0x61,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[0x60, 0x06, 0x66, 0x66, 0x66, 0x66, 0x00, 0x00],
+    &[0x80, 0x31, 0xC0, 0x18, 0x63, 0x8C, 0x31, 0xC6, 0xD8, 0x01],
+    &[
+        0x00, 0x18, 0x60, 0x80, 0x01, 0x86, 0x19, 0x63, 0x86, 0x0D, 0x3E, 0xD8, 0x61, 0x8E, 0x71,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[0x60, 0x66, 0x66, 0x66, 0x66, 0x66, 0x00, 0x00],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xEC, 0x1E, 0x9C, 0x33, 0x8C, 0x31, 0x8C,
+        0x31, 0x8C, 0x31, 0x8C, 0x31, 0x8C, 0x31, 0x8C, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xF6, 0x38, 0x66, 0x98, 0x61, 0x86, 0x19, 0x66, 0x98, 0x61,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x78, 0x30, 0x63, 0x98, 0x61, 0x86, 0x19, 0xC6, 0x0C, 0x1E,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x76, 0x38, 0x63, 0x98, 0x61, 0x86, 0x19, 0xE6, 0x8C, 0x1D,
+        0x06, 0x18, 0x60, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xB8, 0x31, 0x67, 0x98, 0x61, 0x86, 0x19, 0xC6, 0x1C, 0x6E,
+        0x80, 0x01, 0x06, 0x18, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x60, 0x77, 0x18, 0x0C, 0x06, 0x83, 0xC1, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0xC0, 0xC7, 0x90, 0x01, 0x3F, 0xFC, 0x80, 0x09, 0xE3, 0x03, 0x00,
+        0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x06, 0x83, 0xE1, 0x63, 0x30, 0x18, 0x0C, 0x06, 0x03, 0x07, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x86, 0x19, 0x66, 0x98, 0x61, 0x86, 0x19, 0x66, 0x1C, 0x6F,
+        0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x30, 0xD8, 0x98, 0x31, 0x63, 0x6C, 0xD8, 0xE0, 0xC0, 0x01, 0x00,
+        0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC6, 0x98, 0x31, 0xC6, 0xCC, 0xB0, 0x37, 0xEC,
+        0x0D, 0x4A, 0x81, 0x73, 0xE0, 0x1C, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x38, 0xC6, 0x60, 0x03, 0x0E, 0x70, 0xC0, 0x06, 0x63,
+        0x1C, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x83, 0x19, 0x63, 0x8C, 0x31, 0x6C, 0xB0, 0x81, 0x07, 0x0E,
+        0x30, 0x60, 0xE0, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0xE0, 0x0F, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0xF0, 0x07, 0x00,
+        0x00, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x07, 0x0C, 0x60, 0x00, 0x03, 0x18, 0xC0, 0x80, 0x03, 0x30, 0x80, 0x01, 0x0C,
+        0x60, 0x00, 0x03, 0x18, 0x80, 0x03, 0x00,
+    ],
+    &[0x80, 0x31, 0xC6, 0x18, 0x63, 0x8C, 0x31, 0xC6, 0x18, 0x63],
+    &[
+        0x00, 0xE0, 0x00, 0x0C, 0x60, 0x00, 0x03, 0x18, 0xC0, 0x00, 0x1C, 0x30, 0x80, 0x01, 0x0C,
+        0x60, 0x00, 0x03, 0x18, 0x70, 0x00, 0x00,
+    ],
+    &[
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0xE1, 0x3F, 0xC4, 0x03,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    &[],
+]);
+
+pub const DEJAVU_SANS_BOLD_15PX_DIMS: [u8; 96] = [
+    5, 7, 8, 13, 10, 15, 13, 5, 7, 7, 8, 13, 6, 6, 6, 5, 10, 10, 10, 10, 11, 10, 10, 10, 10, 10, 6,
+    6, 13, 13, 13, 9, 15, 11, 11, 11, 12, 10, 10, 12, 12, 6, 6, 11, 10, 14, 12, 13, 11, 13, 11, 11,
+    10, 12, 12, 17, 13, 12, 12, 7, 5, 7, 13, 8, 8, 10, 10, 9, 10, 10, 7, 10, 10, 4, 5, 10, 4, 16,
+    10, 10, 10, 10, 7, 9, 7, 10, 9, 14, 11, 10, 9, 11, 5, 11, 13, 0,
+];
@@ -1,17 +1,63 @@
+use crate::screen::ScreenProfile;
+
+/// Result of placing an element along one axis of the screen.
+/// Elements too large for the screen are never an error: the
+/// position saturates and `overflow` tells by how many pixels
+/// the element sticks out of the screen.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Placement {
+    pub pos: usize,
+    pub overflow: usize,
+}
+
+impl Placement {
+    /// Place an element of `size` pixels at `pos` on an axis of `extent` pixels
+    const fn new(pos: usize, size: usize, extent: usize) -> Placement {
+        Placement {
+            pos,
+            overflow: pos.saturating_add(size).saturating_sub(extent),
+        }
+    }
+
+    pub const fn fits(&self) -> bool {
+        self.overflow == 0
+    }
+}
+
 #[derive(Copy, Clone)]
 pub enum Layout {
     LeftAligned,
     RightAligned,
     Centered,
+    /// Left edge at the given x-coordinate
+    Custom(usize),
 }
 
 impl Layout {
     pub fn get_x(&self, width: usize) -> usize {
-        match self {
-            Layout::LeftAligned => crate::PADDING,
-            Layout::Centered => (crate::SCREEN_WIDTH - width) / 2,
-            Layout::RightAligned => crate::SCREEN_WIDTH - crate::PADDING - width,
-        }
+        self.get_x_margin(width, crate::PADDING)
+    }
+
+    /// Same as `get_x`, keeping `margin` pixels between the element
+    /// and the screen edge it is aligned on
+    pub fn get_x_margin(&self, width: usize, margin: usize) -> usize {
+        self.place_x(width, margin).pos
+    }
+
+    /// Horizontal placement of an element of `width` pixels
+    pub fn place_x(&self, width: usize, margin: usize) -> Placement {
+        let w = ScreenProfile::current().width;
+        let x = match self {
+            Layout::LeftAligned => margin.min(w),
+            Layout::Centered => w.saturating_sub(width) / 2,
+            Layout::RightAligned => w.saturating_sub(margin.saturating_add(width)),
+            Layout::Custom(x) => *x,
+        };
+        let overflow = match self {
+            Layout::RightAligned => margin.saturating_add(width).saturating_sub(w),
+            _ => Placement::new(x, width, w).overflow,
+        };
+        Placement { pos: x, overflow }
     }
 }
 
@@ -25,20 +71,98 @@ pub enum Location {
 
 impl Location {
     pub fn get_y(&self, height: usize) -> usize {
-        match self {
+        self.place_y(height).pos
+    }
+
+    /// Vertical placement of an element of `height` pixels
+    pub fn place_y(&self, height: usize) -> Placement {
+        let h = ScreenProfile::current().height;
+        let y = match self {
             Location::Top => 0,
-            Location::Middle => (crate::SCREEN_HEIGHT - height) / 2,
-            Location::Bottom => crate::SCREEN_HEIGHT - height,
+            Location::Middle => h.saturating_sub(height) / 2,
+            Location::Bottom => h.saturating_sub(height),
             Location::Custom(y) => *y,
+        };
+        Placement::new(y, height, h)
+    }
+
+    /// Top of line `index` out of `count` lines of `line_height` pixels
+    /// stacked at this location, as the MCU draws labels.
+    /// Lines are 2 pixels apart, unless there are more than 4.
+    pub fn line_y(&self, line_height: usize, count: usize, index: usize) -> usize {
+        let pitch = line_height + if count > 4 { 0 } else { 2 };
+        self.get_y(count * pitch) + index * pitch
+    }
+}
+
+/// Left edge and width of the box text is centered in when shifted
+/// by `dx`, as the MCU draws labels. The box is narrowed rather than
+/// moved off screen, so that the text center moves by `dx`.
+pub fn centered_box(screen_width: usize, dx: i16) -> (i16, usize) {
+    let screen_width = screen_width as i16;
+    let shift = dx.clamp(-screen_width / 2, screen_width / 2);
+    (2 * shift.max(0), (screen_width - 2 * shift.abs()) as usize)
+}
+
+/// A rectangle on the screen
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Area {
+    pub x: i32,
+    pub y: i32,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Area {
+    pub const fn new(x: i32, y: i32, width: usize, height: usize) -> Area {
+        Area {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// The whole screen
+    pub fn screen() -> Area {
+        let profile = ScreenProfile::current();
+        Area::new(0, 0, profile.width, profile.height)
+    }
+}
+
+/// Alignment of an element within the room it is given
+#[derive(Copy, Clone)]
+pub enum Align {
+    Start,
+    Center,
+    End,
+}
+
+impl Align {
+    /// Offset of an element of `size` pixels within `room` pixels
+    pub fn offset(&self, size: usize, room: usize) -> usize {
+        match self {
+            Align::Start => 0,
+            Align::Center => room.saturating_sub(size) / 2,
+            Align::End => room.saturating_sub(size),
         }
     }
 }
 
-#[cfg(target_os = "nanos")]
-pub const MAX_LINES: usize = 2;
+/// Elements that can be measured and
+/// drawn at an arbitrary position,
+/// such as the children of a stack
+pub trait Element {
+    /// Width and height in pixels
+    fn size(&self) -> (usize, usize);
+    /// Draw with the top-left corner at `(x, y)`.
+    /// Does not update the screen.
+    fn draw_at(&self, x: i32, y: i32);
+}
 
-#[cfg(not(target_os = "nanos"))]
-pub const MAX_LINES: usize = 3;
+/// Menu entries displayed at once on the compilation target
+#[deprecated(note = "use `ScreenProfile::current().max_lines`")]
+pub const MAX_LINES: usize = crate::screen::TARGET.max_lines;
 
 pub trait Place {
     fn place_pad(&self, loc: Location, layout: Layout, padding: i32);
@@ -65,3 +189,166 @@ pub trait Draw {
     fn display(&self);
     fn erase(&self);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fonts::OPEN_SANS;
+    use crate::screen::{with_device, Device};
+
+    #[test]
+    fn placement_overflow() {
+        assert!(Placement::new(0, 128, 128).fits());
+        assert_eq!(Placement::new(100, 40, 128).overflow, 12);
+        assert_eq!(Placement::new(200, 10, 128).overflow, 82);
+        assert_eq!(
+            Placement::new(usize::MAX, usize::MAX, 128).overflow,
+            usize::MAX - 128
+        );
+    }
+
+    #[test]
+    fn place_x_oversized() {
+        with_device(Device::NanoX, || {
+            let centered = Layout::Centered.place_x(200, 2);
+            assert_eq!(
+                centered,
+                Placement {
+                    pos: 0,
+                    overflow: 72
+                }
+            );
+            let left = Layout::LeftAligned.place_x(200, 2);
+            assert_eq!(
+                left,
+                Placement {
+                    pos: 2,
+                    overflow: 74
+                }
+            );
+            let right = Layout::RightAligned.place_x(200, 2);
+            assert_eq!(
+                right,
+                Placement {
+                    pos: 0,
+                    overflow: 74
+                }
+            );
+            let custom = Layout::Custom(120).place_x(20, 2);
+            assert_eq!(
+                custom,
+                Placement {
+                    pos: 120,
+                    overflow: 12
+                }
+            );
+            assert!(
+                Layout::RightAligned
+                    .place_x(usize::MAX, usize::MAX)
+                    .overflow
+                    > 0
+            );
+            assert_eq!(Layout::LeftAligned.place_x(0, 500).pos, 128);
+        });
+    }
+
+    #[test]
+    fn place_x_fitting() {
+        with_device(Device::NanoX, || {
+            assert_eq!(
+                Layout::Centered.place_x(28, 2),
+                Placement {
+                    pos: 50,
+                    overflow: 0
+                }
+            );
+            assert_eq!(
+                Layout::RightAligned.place_x(28, 2),
+                Placement {
+                    pos: 98,
+                    overflow: 0
+                }
+            );
+            assert_eq!(Layout::LeftAligned.get_x(28), crate::PADDING);
+        });
+    }
+
+    #[test]
+    fn place_y_oversized() {
+        with_device(Device::NanoS, || {
+            assert_eq!(
+                Location::Middle.place_y(40),
+                Placement {
+                    pos: 0,
+                    overflow: 8
+                }
+            );
+            assert_eq!(
+                Location::Bottom.place_y(40),
+                Placement {
+                    pos: 0,
+                    overflow: 8
+                }
+            );
+            assert_eq!(
+                Location::Top.place_y(40),
+                Placement {
+                    pos: 0,
+                    overflow: 8
+                }
+            );
+            assert_eq!(
+                Location::Custom(30).place_y(12),
+                Placement {
+                    pos: 30,
+                    overflow: 10
+                }
+            );
+            assert_eq!(
+                Location::Middle.place_y(12),
+                Placement {
+                    pos: 10,
+                    overflow: 0
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn oversized_text() {
+        let text = "This line is far too long for any screen";
+        let width = OPEN_SANS[0].text_width(text);
+        with_device(Device::NanoSPlus, || {
+            let placement = Layout::Centered.place_x(width, crate::PADDING);
+            assert_eq!(placement.pos, 0);
+            assert_eq!(placement.overflow, width - 128);
+        });
+    }
+
+    #[test]
+    fn align_oversized() {
+        assert_eq!(Align::Center.offset(30, 10), 0);
+        assert_eq!(Align::End.offset(30, 10), 0);
+        assert_eq!(Align::End.offset(4, 10), 6);
+    }
+
+    #[test]
+    fn lines_stacked_like_the_mcu() {
+        with_device(Device::NanoS, || {
+            assert_eq!(Location::Middle.line_y(11, 0, 0), 16);
+            assert_eq!(Location::Top.line_y(11, 0, 0), 0);
+            assert_eq!(Location::Middle.line_y(11, 2, 1), 16);
+            // The first line saturates at the top of the screen
+            assert_eq!(Location::Middle.line_y(11, 5, 0), 0);
+            assert_eq!(Location::Bottom.line_y(11, 5, 4), 44);
+        });
+    }
+
+    #[test]
+    fn centered_box_narrowed() {
+        assert_eq!(centered_box(128, 0), (0, 128));
+        assert_eq!(centered_box(128, 10), (20, 108));
+        assert_eq!(centered_box(128, -10), (0, 108));
+        assert_eq!(centered_box(128, 500), (128, 0));
+    }
+}
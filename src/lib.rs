@@ -2,6 +2,9 @@
 #![feature(cfg_version)]
 #![cfg_attr(not(version("1.63")), feature(array_from_fn))]
 
+#[cfg(test)]
+extern crate std;
+
 pub mod bagls;
 
 #[cfg(not(target_os = "nanos"))]
@@ -11,17 +14,27 @@ pub mod string_se;
 pub mod string_mcu;
 
 pub mod bitmaps;
+pub mod entry;
+pub mod event;
+pub mod executor;
 pub mod fonts;
 pub mod layout;
+pub mod menu;
+#[cfg(feature = "nvm")]
+pub mod nvm;
+mod pic;
+pub mod picker;
+pub mod screen;
 
 pub mod screen_util;
+pub mod settings;
+pub mod stack;
 pub mod ui;
+pub mod words;
 
 pub const PADDING: usize = 2;
-pub const SCREEN_WIDTH: usize = 128;
-
-#[cfg(target_os = "nanos")]
-pub const SCREEN_HEIGHT: usize = 32;
 
-#[cfg(not(target_os = "nanos"))]
-pub const SCREEN_HEIGHT: usize = 64;
+/// Screen dimensions of the compilation target.
+/// Layout code reads `screen::ScreenProfile::current()` instead.
+pub const SCREEN_WIDTH: usize = screen::TARGET.width;
+pub const SCREEN_HEIGHT: usize = screen::TARGET.height;
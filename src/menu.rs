@@ -0,0 +1,146 @@
+use crate::bitmaps;
+use crate::event::EventSource;
+use crate::pic::{pic_call, pic_slice};
+use crate::ui::{GadgetExt, Menu, MenuItem, SephEvents};
+
+/// What happens when an entry of a `MenuTree` is selected
+#[derive(Copy, Clone)]
+pub enum Action<'a> {
+    /// Call the function, then show the same menu again
+    Call(fn()),
+    /// Open a submenu, which gets a "Back" entry
+    Submenu(&'a [Entry<'a>]),
+    /// Leave the menu tree
+    Exit,
+}
+
+#[derive(Copy, Clone)]
+pub struct Entry<'a> {
+    pub item: MenuItem<'a>,
+    pub action: Action<'a>,
+}
+
+impl<'a> Entry<'a> {
+    pub const fn new(item: MenuItem<'a>, action: Action<'a>) -> Self {
+        Entry { item, action }
+    }
+}
+
+/// Largest number of entries of one level of a `MenuTree`
+pub const MAX_ENTRIES: usize = 16;
+
+/// Last entry of submenus and of `Settings`
+pub(crate) const BACK: MenuItem<'static> = MenuItem::new("Back").icon(&bitmaps::BACK);
+
+/// Nested menus navigated by the crate.
+/// Each level remembers its selected entry
+/// while one of its submenus is open.
+#[derive(Copy, Clone)]
+pub struct MenuTree<'a> {
+    root: &'a [Entry<'a>],
+}
+
+impl<'a> MenuTree<'a> {
+    /// Panics if a level has more than `MAX_ENTRIES` entries,
+    /// at compile time when the tree is a constant
+    pub const fn new(root: &'a [Entry<'a>]) -> Self {
+        assert!(fits(root), "more than MAX_ENTRIES entries in a menu level");
+        MenuTree { root }
+    }
+
+    /// Navigate the tree until an `Action::Exit` entry is selected
+    pub fn show(&self) {
+        self.show_with(&mut SephEvents::new());
+    }
+
+    /// Same as `show`, reading events from `source`.
+    /// Returns `None` if `source` runs out of events first.
+    pub fn show_with(&self, source: &mut impl EventSource) -> Option<()> {
+        level(self.root, false, source).map(|_| ())
+    }
+}
+
+/// Whether no level of the tree has more than `MAX_ENTRIES` entries
+const fn fits(entries: &[Entry]) -> bool {
+    if entries.len() > MAX_ENTRIES {
+        return false;
+    }
+    let mut i = 0;
+    while i < entries.len() {
+        if let Action::Submenu(submenu) = entries[i].action {
+            if !fits(submenu) {
+                return false;
+            }
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Show `entries` until one of them leaves the tree, or `Back`
+/// is selected. Returns `true` if the whole tree must be left.
+fn level(entries: &[Entry], back: bool, source: &mut impl EventSource) -> Option<bool> {
+    let entries = pic_slice(entries);
+    let count = entries.len().min(MAX_ENTRIES);
+    let items: [MenuItem; MAX_ENTRIES + 1] = core::array::from_fn(|i| match entries.get(i) {
+        Some(entry) if i < count => entry.item,
+        _ => BACK,
+    });
+    let len = count + back as usize;
+
+    let mut index = 0;
+    loop {
+        index = Menu::from_items(&items[..len])
+            .initial(index)
+            .show_with(source)?;
+        match entries.get(index).filter(|_| index < count) {
+            Some(entry) => match entry.action {
+                Action::Call(action) => pic_call(action),
+                Action::Submenu(submenu) => {
+                    if level(submenu, true, source)? {
+                        return Some(true);
+                    }
+                }
+                Action::Exit => return Some(true),
+            },
+            // Back
+            None => return Some(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{Button, Event, Script};
+    use crate::screen::{with_device, Device};
+
+    const RIGHT: [Event; 2] = [Event::Press(Button::Right), Event::Release(Button::Right)];
+    const BOTH: [Event; 2] = [Event::Press(Button::Both), Event::Release(Button::Both)];
+
+    const SUBMENU: [Entry; 1] = [Entry::new(MenuItem::new("Version"), Action::Call(|| ()))];
+    const ROOT: [Entry; 3] = [
+        Entry::new(MenuItem::new("Ready"), Action::Call(|| ())),
+        Entry::new(MenuItem::new("About"), Action::Submenu(&SUBMENU)),
+        Entry::new(MenuItem::new("Quit"), Action::Exit),
+    ];
+
+    #[test]
+    fn back_restores_the_remembered_index() {
+        // Open "About", select "Back", then "Quit" right after "About"
+        let events = [RIGHT, BOTH, RIGHT, BOTH, RIGHT, BOTH].concat();
+        let left = with_device(Device::NanoX, || {
+            MenuTree::new(&ROOT).show_with(&mut Script::new(&events))
+        });
+        assert_eq!(left, Some(()));
+    }
+
+    #[test]
+    fn too_many_entries() {
+        let entries = [Entry::new(MenuItem::new("Quit"), Action::Exit); MAX_ENTRIES + 1];
+        assert!(fits(&entries[..MAX_ENTRIES]));
+        assert!(!fits(&entries));
+        let nested = [Entry::new(MenuItem::new("More"), Action::Submenu(&entries))];
+        assert!(!fits(&nested));
+    }
+}
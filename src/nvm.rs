@@ -0,0 +1,126 @@
+use core::cell::UnsafeCell;
+use core::num::NonZeroU32;
+
+use crate::settings::SettingsState;
+
+/// Values of a `Settings` gadget kept in NVM, to be placed
+/// in the NVM section of the app:
+/// `#[link_section = ".nvm_data"] static STORE: NvmSettings<2> = NvmSettings::new();`
+/// The version tells values written by an older layout of the
+/// settings apart. It is never 0, which means nothing was written yet.
+#[repr(transparent)]
+pub struct NvmSettings<const N: usize> {
+    /// Only written by `store`
+    data: UnsafeCell<Stored<N>>,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct Stored<const N: usize> {
+    version: u32,
+    values: [u8; N],
+}
+
+// Apps run on a single thread
+unsafe impl<const N: usize> Sync for NvmSettings<N> {}
+
+impl<const N: usize> NvmSettings<N> {
+    pub const fn new() -> Self {
+        NvmSettings {
+            data: UnsafeCell::new(Stored {
+                version: 0,
+                values: [0; N],
+            }),
+        }
+    }
+
+    /// Stored values, or `defaults` if they were not
+    /// written with `version`
+    pub fn load(&self, version: NonZeroU32, defaults: [u8; N]) -> [u8; N] {
+        let stored = unsafe { *ledger_sdk_sys::pic_rs(self).data.get() };
+        if stored.version == version.get() {
+            stored.values
+        } else {
+            defaults
+        }
+    }
+
+    /// Write `values` to NVM.
+    /// Host tests write to memory instead of calling the SDK.
+    pub fn store(&self, version: NonZeroU32, values: [u8; N]) {
+        let version = version.get();
+        #[cfg(test)]
+        unsafe {
+            *self.data.get() = Stored { version, values };
+        }
+        #[cfg(not(test))]
+        unsafe {
+            let mut data = Stored { version, values };
+            ledger_sdk_sys::nvm_write(
+                ledger_sdk_sys::pic(self.data.get() as *mut core::ffi::c_void),
+                &mut data as *mut Stored<N> as *mut core::ffi::c_void,
+                core::mem::size_of::<Stored<N>>() as u32,
+            );
+        }
+    }
+}
+
+impl<const N: usize> Default for NvmSettings<N> {
+    fn default() -> Self {
+        NvmSettings::new()
+    }
+}
+
+/// Settings state backed by `NvmSettings`:
+/// every change is written to NVM right away
+pub struct NvmState<'a, const N: usize> {
+    store: &'a NvmSettings<N>,
+    version: NonZeroU32,
+    values: [u8; N],
+}
+
+impl<'a, const N: usize> NvmState<'a, N> {
+    /// Load the values from `store`, or use `defaults`
+    /// if `store` holds no values of `version`
+    pub fn new(store: &'a NvmSettings<N>, version: NonZeroU32, defaults: [u8; N]) -> Self {
+        let values = store.load(version, defaults);
+        NvmState {
+            store,
+            version,
+            values,
+        }
+    }
+
+    pub fn values(&self) -> [u8; N] {
+        self.values
+    }
+}
+
+impl<const N: usize> SettingsState for NvmState<'_, N> {
+    fn value(&self, index: usize) -> usize {
+        self.values.value(index)
+    }
+
+    fn set_value(&mut self, index: usize, value: usize) {
+        self.values.set_value(index, value);
+        self.store.store(self.version, self.values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn store_then_load() {
+        static STORE: NvmSettings<2> = NvmSettings::new();
+        let version = NonZeroU32::MIN;
+        let mut state = NvmState::new(&STORE, version, [0, 2]);
+        assert_eq!(state.values(), [0, 2]);
+        state.set_value(0, 1);
+        assert_eq!(NvmState::new(&STORE, version, [0, 2]).values(), [1, 2]);
+        // Written by another layout
+        let other = NonZeroU32::new(2).unwrap();
+        assert_eq!(NvmState::new(&STORE, other, [0, 0]).values(), [0, 0]);
+    }
+}
@@ -0,0 +1,28 @@
+//! Apps are linked at fixed addresses but run wherever they are
+//! loaded. Pointers stored in constants and statics, like slices,
+//! strings, function pointers and trait object vtables, hold
+//! link-time addresses and must go through `pic` before use.
+//!
+//! There is no such translation for vtables, so the crate avoids
+//! trait objects and `core::fmt`: enums and generics are used instead.
+
+use core::ffi::c_void;
+
+/// `slice` with its address translated
+pub(crate) fn pic_slice<T>(slice: &[T]) -> &[T] {
+    unsafe {
+        let ptr = ledger_sdk_sys::pic(slice.as_ptr() as *mut c_void) as *const T;
+        core::slice::from_raw_parts(ptr, slice.len())
+    }
+}
+
+/// Same as `pic_slice`, for strings
+pub(crate) fn pic_str(text: &str) -> &str {
+    unsafe { core::str::from_utf8_unchecked(pic_slice(text.as_bytes())) }
+}
+
+/// Call `f` at its translated address
+pub(crate) fn pic_call(f: fn()) {
+    let f: fn() = unsafe { core::mem::transmute(ledger_sdk_sys::pic(f as *mut c_void)) };
+    f()
+}
@@ -0,0 +1,233 @@
+use crate::bagls::{Icon, Label};
+use crate::bitmaps;
+use crate::event::{AutoRepeat, Button, Event};
+use crate::layout::{Area, Draw};
+use crate::screen::ScreenProfile;
+use crate::stack::{Child, VStack};
+use crate::ui::{clear_screen, Gadget, GadgetExt, ItemWriter, SephEvents};
+
+/// Number of repeats after which the step is multiplied by 10
+const ACCELERATE_EVERY: u32 = 5;
+
+/// Pick a number between `min` and `max` with the left (decrease)
+/// and right (increase) buttons, and confirm with both buttons.
+/// Holding a button changes the value faster and faster.
+#[derive(Copy, Clone)]
+pub struct NumberPicker<'a> {
+    title: &'a str,
+    min: u32,
+    max: u32,
+    step: u32,
+    value: u32,
+    repeat: AutoRepeat,
+    /// Repeats since the button was pressed
+    repeats: u32,
+    /// Set once the held button already changed the value,
+    /// so that its release does not change it again
+    held: bool,
+}
+
+impl<'a> NumberPicker<'a> {
+    /// `max` is raised to `min` if lower
+    pub const fn new(min: u32, max: u32) -> Self {
+        NumberPicker {
+            title: "",
+            min,
+            max: if max < min { min } else { max },
+            step: 1,
+            value: min,
+            repeat: AutoRepeat::new(),
+            repeats: 0,
+            held: false,
+        }
+    }
+
+    /// Text displayed above the value
+    pub const fn title(self, title: &'a str) -> Self {
+        NumberPicker { title, ..self }
+    }
+
+    /// Value displayed first, clamped to `min..=max`
+    pub const fn initial(self, value: u32) -> Self {
+        let value = if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            value
+        };
+        NumberPicker { value, ..self }
+    }
+
+    pub const fn step(self, step: u32) -> Self {
+        NumberPicker { step, ..self }
+    }
+
+    pub const fn auto_repeat(self, repeat: AutoRepeat) -> Self {
+        NumberPicker { repeat, ..self }
+    }
+
+    pub fn show(&self) -> u32 {
+        self.show_with(&mut SephEvents::new()).unwrap_or(self.value)
+    }
+
+    /// Step grows tenfold every `ACCELERATE_EVERY` repeats
+    fn current_step(&self) -> u32 {
+        let exponent = (self.repeats / ACCELERATE_EVERY).min(9);
+        self.step.max(1).saturating_mul(10u32.pow(exponent))
+    }
+
+    fn change(&mut self, button: Button) {
+        let step = self.current_step();
+        self.value = match button {
+            Button::Left => self.value.saturating_sub(step).max(self.min),
+            Button::Right => self.value.saturating_add(step).min(self.max),
+            Button::Both => self.value,
+        };
+    }
+}
+
+impl Gadget for NumberPicker<'_> {
+    type Outcome = u32;
+
+    fn render(&self) {
+        clear_screen();
+        let mut buf = [0u8; 10];
+        let value = ItemWriter::new(&mut buf).num(self.value as usize).finish();
+        let labels = [Label::from(self.title), Label::from(value).large()];
+        let lines = labels.each_ref().map(Child::Label);
+        let first = if self.title.is_empty() { 1 } else { 0 };
+        VStack::new(&lines[first..]).place(Area::screen());
+        if self.value > self.min {
+            hint(Button::Left).display();
+        }
+        if self.value < self.max {
+            hint(Button::Right).display();
+        }
+        crate::screen_util::screen_update();
+    }
+
+    fn handle(&mut self, event: Event) -> Option<u32> {
+        match self.repeat.filter(event) {
+            Event::Press(Button::Left) => {
+                self.held = false;
+                self.repeats = 0;
+                pressed_hint(Button::Left).instant_display();
+            }
+            Event::Press(Button::Right) => {
+                self.held = false;
+                self.repeats = 0;
+                pressed_hint(Button::Right).instant_display();
+            }
+            Event::LongPress(button) | Event::Repeat(button) if button != Button::Both => {
+                self.held = true;
+                self.change(button);
+                self.repeats = self.repeats.saturating_add(1);
+                self.render();
+                pressed_hint(button).instant_display();
+            }
+            Event::Release(Button::Both) => return Some(self.value),
+            Event::Release(button) => {
+                if !self.held {
+                    self.change(button);
+                }
+                self.render();
+            }
+            _ => (),
+        }
+        None
+    }
+}
+
+/// Hint next to `button`: a down arrow on the left (decrease)
+/// and an up arrow on the right (increase)
+fn hint(button: Button) -> Icon<'static> {
+    let arrows = ScreenProfile::current().arrows;
+    match button {
+        Button::Left => Icon::new(&bitmaps::DOWN_ARROW, arrows.up),
+        _ => Icon::new(&bitmaps::UP_ARROW, arrows.down),
+    }
+}
+
+/// Hint displayed while `button` is pressed, moved in its direction
+fn pressed_hint(button: Button) -> Icon<'static> {
+    match button {
+        Button::Left => hint(button).shift_v(4),
+        _ => hint(button).shift_v(-4),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Script;
+    use crate::screen::{with_device, Device};
+    use crate::screen_util::stub;
+    use crate::ui::tests::assert_fits;
+
+    #[test]
+    fn large_value_fits_every_device() {
+        let picker = NumberPicker::new(0, u32::MAX)
+            .title("Amount")
+            .initial(u32::MAX);
+        assert_fits(&picker);
+        with_device(Device::NanoX, || {
+            stub::take();
+            picker.render();
+            assert!(stub::take().iter().any(|area| area.height == 16));
+        });
+    }
+
+    #[test]
+    fn hints_only_where_the_value_can_change() {
+        with_device(Device::NanoX, || {
+            for (value, left, right) in [(0, false, true), (5, true, true), (10, true, false)] {
+                stub::take();
+                NumberPicker::new(0, 10).initial(value).render();
+                let drawn = stub::take();
+                assert_eq!(drawn.contains(&hint(Button::Left).bounds()), left);
+                assert_eq!(drawn.contains(&hint(Button::Right).bounds()), right);
+            }
+            // Decreasing is hinted by a down arrow on the left
+            assert_eq!(hint(Button::Left).icon.bitmap, bitmaps::DOWN_ARROW.bitmap);
+            assert_eq!(hint(Button::Left).bounds().x, 2);
+        });
+    }
+
+    const LEFT: [Event; 2] = [Event::Press(Button::Left), Event::Release(Button::Left)];
+    const RIGHT: [Event; 2] = [Event::Press(Button::Right), Event::Release(Button::Right)];
+    const BOTH: [Event; 2] = [Event::Press(Button::Both), Event::Release(Button::Both)];
+
+    fn pick(picker: NumberPicker, events: &[Event]) -> Option<u32> {
+        with_device(Device::NanoX, || picker.show_with(&mut Script::new(events)))
+    }
+
+    #[test]
+    fn clamped_to_min_and_max() {
+        let picker = NumberPicker::new(3, 5).initial(4);
+        assert_eq!(pick(picker, &[LEFT, LEFT, LEFT, BOTH].concat()), Some(3));
+        assert_eq!(pick(picker, &[RIGHT, RIGHT, RIGHT, BOTH].concat()), Some(5));
+        assert_eq!(pick(NumberPicker::new(0, 10).initial(20), &BOTH), Some(10));
+    }
+
+    #[test]
+    fn changed_by_step() {
+        let picker = NumberPicker::new(0, 100).step(10);
+        assert_eq!(pick(picker, &[RIGHT, RIGHT, LEFT, BOTH].concat()), Some(10));
+        // The last step is shortened to stay within bounds
+        let picker = NumberPicker::new(0, 25).step(10).initial(20);
+        assert_eq!(pick(picker, &[RIGHT, BOTH].concat()), Some(25));
+    }
+
+    #[test]
+    fn long_press_accelerates() {
+        let repeat = AutoRepeat::new().delay(100).rate(100);
+        let picker = NumberPicker::new(0, 1_000_000).auto_repeat(repeat);
+        let mut events = [Event::Ticker; 14];
+        events[0] = Event::Press(Button::Right);
+        events[13] = Event::Release(Button::Right);
+        // 5 steps of 1, 5 steps of 10, then steps of 100.
+        // Releasing the held button does not change the value again.
+        assert_eq!(pick(picker, &[&events[..], &BOTH].concat()), Some(255));
+    }
+}
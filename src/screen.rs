@@ -0,0 +1,168 @@
+use crate::fonts::{Font, LARGE, OPEN_SANS};
+
+/// Geometry of a device screen.
+/// Layout and gadget code read the current profile
+/// instead of relying on the compilation target.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScreenProfile {
+    pub width: usize,
+    pub height: usize,
+    /// Number of menu entries displayed at once
+    pub max_lines: usize,
+    /// Height of a line of text in the default font
+    pub line_height: usize,
+    /// Index in `fonts::OPEN_SANS` of the default font,
+    /// followed by its bold variant
+    pub font: usize,
+    /// Top-left corners of the navigation arrows
+    pub arrows: ArrowPositions,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ArrowPositions {
+    pub up: (i16, i16),
+    pub down: (i16, i16),
+    pub left: (i16, i16),
+    pub right: (i16, i16),
+}
+
+/// Arrows are 7x4 (up, down) or 4x7 (left, right),
+/// vertically centered and 2 pixels away from the screen edges
+const fn arrows(width: i16, height: i16) -> ArrowPositions {
+    ArrowPositions {
+        up: (2, (height - 4) / 2),
+        down: (width - 2 - 7, (height - 4) / 2),
+        left: (2, (height - 7) / 2),
+        right: (width - 2 - 4, (height - 7) / 2),
+    }
+}
+
+pub const NANOS: ScreenProfile = ScreenProfile {
+    width: 128,
+    height: 32,
+    max_lines: 2,
+    line_height: 11,
+    font: 0,
+    arrows: arrows(128, 32),
+};
+
+pub const NANOX: ScreenProfile = ScreenProfile {
+    width: 128,
+    height: 64,
+    max_lines: 3,
+    line_height: 12,
+    font: 0,
+    arrows: arrows(128, 64),
+};
+
+pub const NANOSPLUS: ScreenProfile = ScreenProfile {
+    width: 128,
+    height: 64,
+    max_lines: 3,
+    line_height: 12,
+    font: 0,
+    arrows: arrows(128, 64),
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Device {
+    NanoS = 1,
+    NanoX,
+    NanoSPlus,
+}
+
+impl Device {
+    pub const fn profile(self) -> ScreenProfile {
+        match self {
+            Device::NanoS => NANOS,
+            Device::NanoX => NANOX,
+            Device::NanoSPlus => NANOSPLUS,
+        }
+    }
+}
+
+/// Profile of the device the crate is built for
+#[cfg(target_os = "nanos")]
+pub const TARGET: ScreenProfile = NANOS;
+#[cfg(target_os = "nanox")]
+pub const TARGET: ScreenProfile = NANOX;
+#[cfg(not(any(target_os = "nanos", target_os = "nanox")))]
+pub const TARGET: ScreenProfile = NANOSPLUS;
+
+#[cfg(test)]
+std::thread_local! {
+    /// Device selected with `with_device`, instead of the compilation target
+    static SELECTED: core::cell::Cell<Option<Device>> = const { core::cell::Cell::new(None) };
+}
+
+impl ScreenProfile {
+    /// Largest `max_lines` of all profiles,
+    /// to size buffers of menu entries
+    pub const MAX_LINES: usize = {
+        let profiles = [NANOS, NANOX, NANOSPLUS];
+        let mut max = 0;
+        let mut i = 0;
+        while i < profiles.len() {
+            if profiles[i].max_lines > max {
+                max = profiles[i].max_lines;
+            }
+            i += 1;
+        }
+        max
+    };
+
+    /// Default font, or its bold variant
+    pub fn text_font(&self, bold: bool) -> &'static Font {
+        let index = self.font + bold as usize;
+        OPEN_SANS.get(index).unwrap_or(&OPEN_SANS[bold as usize])
+    }
+
+    /// Font of large labels, e.g. a single value or word
+    pub fn large_font(&self) -> &'static Font {
+        &LARGE
+    }
+
+    /// Profile read by layout and gadget code
+    pub fn current() -> ScreenProfile {
+        #[cfg(test)]
+        if let Some(device) = SELECTED.with(|selected| selected.get()) {
+            return device.profile();
+        }
+        TARGET
+    }
+}
+
+/// Run `f` laid out for `device` instead of the compilation target,
+/// e.g. to check gadget layouts for every device on the host.
+/// The previous device is selected again afterwards.
+#[cfg(test)]
+pub(crate) fn with_device<R>(device: Device, f: impl FnOnce() -> R) -> R {
+    /// Selects the previous device again, even if `f` panics
+    struct Restore(Option<Device>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SELECTED.with(|selected| selected.set(self.0));
+        }
+    }
+
+    let _restore = Restore(SELECTED.with(|selected| selected.replace(Some(device))));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_device_restores_the_previous_one() {
+        with_device(Device::NanoS, || {
+            with_device(Device::NanoX, || {
+                assert_eq!(ScreenProfile::current(), NANOX)
+            });
+            assert_eq!(ScreenProfile::current(), NANOS);
+        });
+        assert_eq!(ScreenProfile::current(), TARGET);
+    }
+}
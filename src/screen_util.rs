@@ -2,35 +2,94 @@
 
 use ledger_sdk_sys;
 
+#[cfg_attr(test, allow(unused_variables))]
 pub fn draw(x_pos: i32, y_pos: i32, w: u32, h: u32, inv: bool, bmp: &[u8]) {
-    let inverted = [inv as u32, !inv as u32];
+    #[cfg(test)]
+    stub::record(crate::layout::Area::new(
+        x_pos, y_pos, w as usize, h as usize,
+    ));
+    #[cfg(not(test))]
     unsafe {
+        let inverted = [inv as u32, !inv as u32];
         ledger_sdk_sys::bagl_hal_draw_bitmap_within_rect(
-            x_pos, 
-            y_pos, 
-            w, 
-            h, 
-            2, 
-            inverted.as_ptr(), 
-            1, 
-            bmp.as_ptr(), 
-            w * h);
+            x_pos,
+            y_pos,
+            w,
+            h,
+            2,
+            inverted.as_ptr(),
+            1,
+            bmp.as_ptr(),
+            w * h,
+        );
     }
 }
 
 pub fn fulldraw(x_pos: i32, y_pos: i32, bmp: &[u8]) {
-    draw(x_pos, y_pos, 128, 64, false, bmp);
+    let profile = crate::screen::ScreenProfile::current();
+    draw(
+        x_pos,
+        y_pos,
+        profile.width as u32,
+        profile.height as u32,
+        false,
+        bmp,
+    );
+}
+
+/// Fill (`color` 1) or clear (`color` 0) a rectangle
+#[cfg_attr(test, allow(unused_variables))]
+pub(crate) fn draw_rect(color: u32, x_pos: i32, y_pos: i32, w: u32, h: u32) {
+    #[cfg(test)]
+    stub::record(crate::layout::Area::new(
+        x_pos, y_pos, w as usize, h as usize,
+    ));
+    #[cfg(not(test))]
+    unsafe {
+        ledger_sdk_sys::bagl_hal_draw_rect(color, x_pos, y_pos, w, h);
+    }
 }
 
 pub fn screen_update() {
-    #[cfg(not(target_os = "nanos"))]
+    #[cfg(not(any(target_os = "nanos", test)))]
     unsafe {
         ledger_sdk_sys::screen_update();
     }
 }
 
+/// Clear the screen with the SE syscall.
+/// Speculos does not emulate it, see `ui::clear_screen`.
+pub(crate) fn screen_clear() {
+    #[cfg(not(test))]
+    unsafe {
+        ledger_sdk_sys::screen_clear();
+    }
+}
+
 #[cfg(not(feature = "speculos"))]
 pub fn seph_setup_ticker(interval_ms: u16) {
     let ms = interval_ms.to_be_bytes();
     ledger_sdk_sys::seph::seph_send(&[0x4e, 0, 2, ms[0], ms[1]]);
 }
+
+/// Stand-in for the screen in host tests: drawing functions
+/// record the area they touch instead of calling the SDK
+#[cfg(test)]
+pub(crate) mod stub {
+    use crate::layout::Area;
+    use std::cell::RefCell;
+    use std::vec::Vec;
+
+    std::thread_local! {
+        static DRAWN: RefCell<Vec<Area>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(crate) fn record(area: Area) {
+        DRAWN.with(|drawn| drawn.borrow_mut().push(area));
+    }
+
+    /// Areas drawn on this thread since the last call
+    pub(crate) fn take() -> Vec<Area> {
+        DRAWN.with(|drawn| drawn.take())
+    }
+}
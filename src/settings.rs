@@ -0,0 +1,152 @@
+use crate::bagls::{Arrow, Label};
+use crate::event::{Button, Event};
+use crate::layout::{Area, Draw};
+use crate::menu::BACK;
+use crate::pic::pic_slice;
+use crate::stack::{Child, VStack};
+use crate::ui::{clear_screen, Gadget, GadgetExt, SephEvents};
+
+const ENABLED: [&str; 2] = ["Disabled", "Enabled"];
+
+#[derive(Copy, Clone)]
+enum Kind<'a> {
+    Toggle,
+    Choice(&'a [&'a str]),
+}
+
+/// An entry of the `Settings` gadget
+#[derive(Copy, Clone)]
+pub struct Setting<'a> {
+    name: &'a str,
+    kind: Kind<'a>,
+}
+
+impl<'a> Setting<'a> {
+    /// "Enabled" or "Disabled", stored as 1 or 0
+    pub const fn toggle(name: &'a str) -> Self {
+        Setting {
+            name,
+            kind: Kind::Toggle,
+        }
+    }
+
+    /// One of `choices`, stored as its index
+    pub const fn choice(name: &'a str, choices: &'a [&'a str]) -> Self {
+        Setting {
+            name,
+            kind: Kind::Choice(choices),
+        }
+    }
+
+    fn choices(&self) -> &'a [&'a str] {
+        match self.kind {
+            Kind::Toggle => &ENABLED,
+            Kind::Choice(choices) => pic_slice(choices),
+        }
+    }
+}
+
+/// Caller-provided storage for the values of the settings
+pub trait SettingsState {
+    /// Value of setting `index`
+    fn value(&self, index: usize) -> usize;
+    fn set_value(&mut self, index: usize, value: usize);
+}
+
+impl<const N: usize> SettingsState for [u8; N] {
+    fn value(&self, index: usize) -> usize {
+        self.get(index).copied().unwrap_or(0) as usize
+    }
+
+    fn set_value(&mut self, index: usize, value: usize) {
+        if let Some(v) = self.get_mut(index) {
+            *v = value as u8;
+        }
+    }
+}
+
+/// One setting per screen, with its name and current value.
+/// Pressing both buttons toggles the value or cycles through
+/// the choices, and updates the state right away.
+/// A last "Back" entry leaves the gadget.
+pub struct Settings<'a, S: SettingsState> {
+    entries: &'a [Setting<'a>],
+    state: &'a mut S,
+    index: usize,
+}
+
+impl<'a, S: SettingsState> Settings<'a, S> {
+    pub fn new(entries: &'a [Setting<'a>], state: &'a mut S) -> Self {
+        Settings {
+            entries: pic_slice(entries),
+            state,
+            index: 0,
+        }
+    }
+
+    pub fn show(self) {
+        self.show_with(&mut SephEvents::new());
+    }
+
+    /// Current value of setting `index`, as displayed
+    fn value(&self, index: usize) -> &'a str {
+        let choices = self.entries[index].choices();
+        let value = self.state.value(index);
+        choices.get(value).copied().unwrap_or("")
+    }
+}
+
+impl<S: SettingsState> Gadget for Settings<'_, S> {
+    type Outcome = ();
+
+    fn render(&self) {
+        clear_screen();
+        match self.entries.get(self.index) {
+            Some(setting) => {
+                let labels = [
+                    Label::from(setting.name).bold(),
+                    Label::from(self.value(self.index)),
+                ];
+                let lines = labels.each_ref().map(Child::Label);
+                VStack::new(&lines).place(Area::screen());
+            }
+            None => BACK.draw(0),
+        }
+        if self.index > 0 {
+            Arrow::Left.icon().display();
+        }
+        if self.index < self.entries.len() {
+            Arrow::Right.icon().display();
+        }
+        crate::screen_util::screen_update();
+    }
+
+    fn handle(&mut self, event: Event) -> Option<()> {
+        match event {
+            Event::Press(Button::Left) => Arrow::Left.pressed().instant_display(),
+            Event::Press(Button::Right) => Arrow::Right.pressed().instant_display(),
+            Event::Release(Button::Left) => {
+                self.index = self.index.saturating_sub(1);
+                self.render();
+            }
+            Event::Release(Button::Right) => {
+                if self.index < self.entries.len() {
+                    self.index += 1;
+                }
+                self.render();
+            }
+            Event::Release(Button::Both) => {
+                let Some(setting) = self.entries.get(self.index) else {
+                    // Back
+                    return Some(());
+                };
+                let count = setting.choices().len().max(1);
+                let value = (self.state.value(self.index) + 1) % count;
+                self.state.set_value(self.index, value);
+                self.render();
+            }
+            _ => (),
+        }
+        None
+    }
+}
@@ -0,0 +1,241 @@
+use crate::bagls::{Icon, Label, RectFull};
+use crate::layout::{Align, Area, Element};
+
+/// Element placed by a stack.
+/// An enum rather than `&dyn Element`, see `crate::pic`.
+#[derive(Copy, Clone)]
+pub enum Child<'a> {
+    Label(&'a Label<'a>),
+    Icon(&'a Icon<'a>),
+    Rect(&'a RectFull),
+    VStack(&'a VStack<'a>),
+    HStack(&'a HStack<'a>),
+}
+
+impl Element for Child<'_> {
+    fn size(&self) -> (usize, usize) {
+        match self {
+            Child::Label(label) => label.size(),
+            Child::Icon(icon) => icon.size(),
+            Child::Rect(rect) => rect.size(),
+            Child::VStack(stack) => stack.size(),
+            Child::HStack(stack) => stack.size(),
+        }
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        match self {
+            Child::Label(label) => label.draw_at(x, y),
+            Child::Icon(icon) => icon.draw_at(x, y),
+            Child::Rect(rect) => rect.draw_at(x, y),
+            Child::VStack(stack) => stack.draw_at(x, y),
+            Child::HStack(stack) => stack.draw_at(x, y),
+        }
+    }
+}
+
+/// Children placed from top to bottom.
+/// Children are drawn where the stack puts them:
+/// their own location and layout are ignored.
+pub struct VStack<'a> {
+    children: &'a [Child<'a>],
+    spacing: usize,
+    align: Align,
+    justify: Align,
+}
+
+impl<'a> VStack<'a> {
+    pub const fn new(children: &'a [Child<'a>]) -> Self {
+        VStack {
+            children,
+            spacing: crate::PADDING,
+            align: Align::Center,
+            justify: Align::Center,
+        }
+    }
+
+    /// Vertical space in pixels between two children
+    pub const fn spacing(self, spacing: usize) -> Self {
+        VStack { spacing, ..self }
+    }
+
+    /// Horizontal alignment of each child
+    pub const fn align(self, align: Align) -> Self {
+        VStack { align, ..self }
+    }
+
+    /// Vertical alignment of the whole stack
+    pub const fn justify(self, justify: Align) -> Self {
+        VStack { justify, ..self }
+    }
+
+    /// Draw the children within `area`.
+    /// Does not update the screen.
+    pub fn place(&self, area: Area) {
+        let height = self.size().1;
+        let mut y = area.y + self.justify.offset(height, area.height) as i32;
+        for child in self.children.iter() {
+            let (w, h) = child.size();
+            let x = area.x + self.align.offset(w, area.width) as i32;
+            child.draw_at(x, y);
+            y += (h + self.spacing) as i32;
+        }
+    }
+}
+
+impl Element for VStack<'_> {
+    fn size(&self) -> (usize, usize) {
+        let gaps = self.spacing * self.children.len().saturating_sub(1);
+        self.children.iter().fold((0, gaps), |(w, h), child| {
+            let (cw, ch) = child.size();
+            (w.max(cw), h + ch)
+        })
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        let (width, height) = self.size();
+        self.place(Area::new(x, y, width, height));
+    }
+}
+
+/// Children placed from left to right.
+/// Children are drawn where the stack puts them:
+/// their own location and layout are ignored.
+pub struct HStack<'a> {
+    children: &'a [Child<'a>],
+    spacing: usize,
+    align: Align,
+    justify: Align,
+}
+
+impl<'a> HStack<'a> {
+    pub const fn new(children: &'a [Child<'a>]) -> Self {
+        HStack {
+            children,
+            spacing: crate::PADDING,
+            align: Align::Center,
+            justify: Align::Center,
+        }
+    }
+
+    /// Horizontal space in pixels between two children
+    pub const fn spacing(self, spacing: usize) -> Self {
+        HStack { spacing, ..self }
+    }
+
+    /// Vertical alignment of each child
+    pub const fn align(self, align: Align) -> Self {
+        HStack { align, ..self }
+    }
+
+    /// Horizontal alignment of the whole stack
+    pub const fn justify(self, justify: Align) -> Self {
+        HStack { justify, ..self }
+    }
+
+    /// Draw the children within `area`.
+    /// Does not update the screen.
+    pub fn place(&self, area: Area) {
+        let width = self.size().0;
+        let mut x = area.x + self.justify.offset(width, area.width) as i32;
+        for child in self.children.iter() {
+            let (w, h) = child.size();
+            let y = area.y + self.align.offset(h, area.height) as i32;
+            child.draw_at(x, y);
+            x += (w + self.spacing) as i32;
+        }
+    }
+}
+
+impl Element for HStack<'_> {
+    fn size(&self) -> (usize, usize) {
+        let gaps = self.spacing * self.children.len().saturating_sub(1);
+        self.children.iter().fold((gaps, 0), |(w, h), child| {
+            let (cw, ch) = child.size();
+            (w + cw, h.max(ch))
+        })
+    }
+
+    fn draw_at(&self, x: i32, y: i32) {
+        let (width, height) = self.size();
+        self.place(Area::new(x, y, width, height));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screen::with_device;
+    use crate::screen_util::stub;
+    use crate::ui::tests::DEVICES;
+    use std::vec::Vec;
+
+    const AREA: Area = Area::new(0, 0, 40, 30);
+
+    /// Areas drawn by `place`, the same on every device
+    fn placed(place: impl Fn()) -> Vec<Area> {
+        let drawn = DEVICES.map(|device| {
+            with_device(device, || {
+                stub::take();
+                place();
+                stub::take()
+            })
+        });
+        assert!(drawn.iter().all(|areas| *areas == drawn[0]));
+        drawn[0].clone()
+    }
+
+    #[test]
+    fn vstack_placement() {
+        let small = RectFull::new().width(10).height(4);
+        let large = RectFull::new().width(20).height(6);
+        let children = [Child::Rect(&small), Child::Rect(&large)];
+        let stack = VStack::new(&children).spacing(3);
+        assert_eq!(stack.size(), (20, 13));
+        assert_eq!(
+            placed(|| stack.place(AREA)),
+            [Area::new(15, 8, 10, 4), Area::new(10, 15, 20, 6)]
+        );
+        let stack = stack.align(Align::End).justify(Align::Start);
+        assert_eq!(
+            placed(|| stack.place(AREA)),
+            [Area::new(30, 0, 10, 4), Area::new(20, 7, 20, 6)]
+        );
+    }
+
+    #[test]
+    fn hstack_placement() {
+        let small = RectFull::new().width(10).height(4);
+        let large = RectFull::new().width(20).height(6);
+        let children = [Child::Rect(&small), Child::Rect(&large)];
+        let stack = HStack::new(&children).spacing(2);
+        assert_eq!(stack.size(), (32, 6));
+        assert_eq!(
+            placed(|| stack.place(AREA)),
+            [Area::new(4, 13, 10, 4), Area::new(16, 12, 20, 6)]
+        );
+        let stack = stack.align(Align::End).justify(Align::End);
+        assert_eq!(
+            placed(|| stack.place(AREA)),
+            [Area::new(8, 26, 10, 4), Area::new(20, 24, 20, 6)]
+        );
+    }
+
+    #[test]
+    fn nested_stacks() {
+        let rect = RectFull::new().width(4).height(4);
+        let row = [Child::Rect(&rect), Child::Rect(&rect)];
+        let row = HStack::new(&row).spacing(2);
+        let column = [Child::HStack(&row), Child::Rect(&rect)];
+        let column = VStack::new(&column).spacing(1).align(Align::Start);
+        assert_eq!(column.size(), (10, 9));
+        assert_eq!(
+            placed(|| column.place(AREA)),
+            [
+                Area::new(0, 10, 4, 4),
+                Area::new(6, 10, 4, 4),
+                Area::new(0, 15, 4, 4),
+            ]
+        );
+    }
+}
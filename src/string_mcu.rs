@@ -1,5 +1,6 @@
 use crate::bagls::*;
 use crate::layout::*;
+use crate::screen::ScreenProfile;
 
 impl StringPlace for &str {
     // unused when using MCU display
@@ -8,11 +9,7 @@ impl StringPlace for &str {
     }
 
     fn place(&self, loc: Location, layout: Layout, bold: bool) {
-        let mut lbl = Label::new()
-            .dims(128, 11)
-            .location(loc)
-            .layout(layout)
-            .text(self);
+        let mut lbl = Label::new().location(loc).layout(layout).text(self);
         if bold {
             lbl = lbl.bold();
         }
@@ -20,6 +17,14 @@ impl StringPlace for &str {
     }
 }
 
+/// Draw `text` with its top-left corner at `(x, y)`
+pub fn place_at(text: &str, x: i32, y: i32, bold: bool) {
+    let profile = ScreenProfile::current();
+    let width = profile.text_font(bold).text_width(text);
+    let height = profile.line_height;
+    paint_text(text, x as i16, y as i16, width as u16, height as u16, bold);
+}
+
 impl StringPlace for [&str] {
     // unused when using MCU display
     fn compute_width(&self, _bold: bool) -> usize {
@@ -27,7 +32,7 @@ impl StringPlace for [&str] {
     }
 
     fn place(&self, loc: Location, layout: Layout, bold: bool) {
-        let c_height = 11; // Default font height
+        let c_height = ScreenProfile::current().line_height;
         let total_height = self.len() * c_height;
         let mut cur_y = loc.get_y(total_height);
         for string in self.iter() {
@@ -43,7 +48,14 @@ impl<'a> StringPlace for Label<'a> {
     }
 
     fn place(&self, loc: Location, layout: Layout, bold: bool) {
-        self.text.place(loc, layout, bold);
+        let mut lbl = Label::new()
+            .location(loc)
+            .layout(layout)
+            .margin(self.margin)
+            .offset(self.offset.0, self.offset.1)
+            .text(self.text);
+        lbl.bold = bold;
+        lbl.paint();
     }
 }
 
@@ -53,13 +65,16 @@ impl<'a> StringPlace for [Label<'a>] {
             .fold(0, |acc, lbl| acc.max(lbl.compute_width(bold)))
     }
 
-    fn place(&self, loc: Location, layout: Layout, _bold: bool) {
-        let padding = if self.len() > 4 { 0 } else { 2 };
-        let total_height = self.iter().fold(0, |acc, _| acc + padding + 11);
-        let mut cur_y = loc.get_y(total_height);
-        for label in self.iter() {
-            label.place(Location::Custom(cur_y), layout, label.bold);
-            cur_y += 11 + padding;
+    fn place(&self, loc: Location, layout: Layout, bold: bool) {
+        for (index, label) in self.iter().enumerate() {
+            let y = line_y(loc, bold, self.len(), index);
+            label.place(Location::Custom(y), layout, label.bold);
         }
     }
 }
+
+/// Top of line `index` when placing `count` labels
+/// with `[Label]::place`
+pub(crate) fn line_y(loc: Location, _bold: bool, count: usize, index: usize) -> usize {
+    loc.line_y(ScreenProfile::current().line_height, count, index)
+}
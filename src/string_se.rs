@@ -1,57 +1,58 @@
-use crate::fonts::OPEN_SANS;
+use crate::fonts::Font;
 use crate::layout::*;
+use crate::screen::ScreenProfile;
 use crate::screen_util::{draw, screen_update};
 use core::ffi::c_void;
 use ledger_sdk_sys;
 
-extern "C" {
-    fn pic(link_address: *mut c_void) -> *mut c_void;
-}
-
 impl StringPlace for &str {
     fn compute_width(&self, bold: bool) -> usize {
-        let font_choice = bold as usize;
-        self.as_bytes().iter().map(ledger_sdk_sys::pic_rs).fold(0, |acc, c| {
-            acc + OPEN_SANS[font_choice].dims[*c as usize - 0x20] as usize
-        })
+        ScreenProfile::current().text_font(bold).text_width(self)
     }
 
     fn place(&self, loc: Location, layout: Layout, bold: bool) {
         let total_width = self.compute_width(bold);
-        let mut cur_x = layout.get_x(total_width as usize) as i32;
-
-        let font_choice = bold as usize;
-        for c in self.as_bytes().iter().map(ledger_sdk_sys::pic_rs) {
-            let offset_c = *c as usize - 0x20;
-            let character = unsafe {
-                let tmp = pic(OPEN_SANS[font_choice].chars.0[offset_c].as_ptr() as *mut c_void)
-                    as *const u8;
-                core::slice::from_raw_parts(tmp, OPEN_SANS[font_choice].chars.0[offset_c].len())
-            };
-            let c_width = OPEN_SANS[font_choice].dims[offset_c];
-            let c_height = OPEN_SANS[font_choice].height as usize;
-            let y = loc.get_y(c_height);
-            draw(
-                cur_x,
-                y as i32,
-                c_width as u32,
-                c_height as u32,
-                false,
-                character,
-            );
-            cur_x += c_width as i32;
-        }
+        let x = layout.get_x(total_width) as i32;
+        let y = loc.get_y(ScreenProfile::current().text_font(bold).height as usize) as i32;
+        place_at(self, x, y, bold);
         screen_update();
     }
 }
 
+/// Draw `text` with its top-left corner at `(x, y)`.
+/// Does not update the screen.
+pub fn place_at(text: &str, x: i32, y: i32, bold: bool) {
+    draw_text(text, x, y, ScreenProfile::current().text_font(bold));
+}
+
+/// Draw `text` with `font` and its top-left corner at `(x, y)`
+pub(crate) fn draw_text(text: &str, x: i32, y: i32, font: &Font) {
+    let mut cur_x = x;
+    for c in text.as_bytes().iter().map(ledger_sdk_sys::pic_rs) {
+        // Characters missing from the font are skipped
+        let offset_c = match (*c as usize).checked_sub(0x20) {
+            Some(offset_c) if offset_c < font.dims.len() => offset_c,
+            _ => continue,
+        };
+        let character = unsafe {
+            let tmp =
+                ledger_sdk_sys::pic(font.chars.0[offset_c].as_ptr() as *mut c_void) as *const u8;
+            core::slice::from_raw_parts(tmp, font.chars.0[offset_c].len())
+        };
+        let c_width = font.dims[offset_c];
+        let c_height = font.height as usize;
+        draw(cur_x, y, c_width as u32, c_height as u32, false, character);
+        cur_x += c_width as i32;
+    }
+}
+
 impl StringPlace for [&str] {
     fn compute_width(&self, bold: bool) -> usize {
         self.iter().fold(0, |acc, s| acc.max(s.compute_width(bold)))
     }
 
     fn place(&self, loc: Location, layout: Layout, bold: bool) {
-        let c_height = OPEN_SANS[bold as usize].height as usize;
+        let c_height = ScreenProfile::current().text_font(bold).height as usize;
         let padding = if self.len() > 4 { 0 } else { 2 };
         let total_height = self.len() * (c_height + padding);
         let mut cur_y = loc.get_y(total_height);
@@ -66,11 +67,13 @@ use crate::bagls::se::Label;
 
 impl<'a> StringPlace for Label<'a> {
     fn compute_width(&self, _bold: bool) -> usize {
-        self.text.compute_width(self.bold)
+        self.font(self.bold).text_width(self.text)
     }
 
     fn place(&self, loc: Location, layout: Layout, bold: bool) {
-        self.text.place(loc, layout, bold);
+        let (x, y) = self.origin(loc, layout, bold);
+        draw_text(self.text, x, y, self.font(bold));
+        screen_update();
     }
 }
 
@@ -80,13 +83,64 @@ impl<'a> StringPlace for [Label<'a>] {
             .fold(0, |acc, lbl| acc.max(lbl.compute_width(bold)))
     }
 
-    fn place(&self, _loc: Location, layout: Layout, bold: bool) {
-        let c_height = OPEN_SANS[bold as usize].height as usize;
-        let padding = ((crate::SCREEN_HEIGHT / self.len()) - c_height) / 2;
-        let mut cur_y = padding;
-        for label in self.iter() {
-            label.place(Location::Custom(cur_y), layout, label.bold);
-            cur_y += c_height + 2 * padding;
+    fn place(&self, loc: Location, layout: Layout, bold: bool) {
+        for (index, label) in self.iter().enumerate() {
+            let y = line_y(loc, bold, self.len(), index);
+            label.place(Location::Custom(y), layout, label.bold);
         }
     }
 }
+
+/// Top of line `index` when placing `count` labels
+/// with `[Label]::place`. The lines always fill the screen.
+pub(crate) fn line_y(_loc: Location, bold: bool, count: usize, index: usize) -> usize {
+    let c_height = ScreenProfile::current().text_font(bold).height as usize;
+    let screen_height = ScreenProfile::current().height;
+    let padding = (screen_height / count.max(1)).saturating_sub(c_height) / 2;
+    padding + index * (c_height + 2 * padding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::screen::{with_device, Device};
+
+    #[test]
+    fn line_y_no_lines() {
+        with_device(Device::NanoX, || {
+            assert_eq!(line_y(Location::Middle, false, 0, 0), 26);
+            assert_eq!(
+                line_y(Location::Middle, false, 0, 0),
+                line_y(Location::Middle, false, 1, 0)
+            );
+        });
+    }
+
+    #[test]
+    fn line_y_fills_screen() {
+        with_device(Device::NanoX, || {
+            assert_eq!(line_y(Location::Middle, false, 2, 0), 10);
+            assert_eq!(line_y(Location::Middle, false, 2, 1), 42);
+            assert_eq!(line_y(Location::Middle, false, 3, 2), 44);
+        });
+    }
+
+    #[test]
+    fn line_y_too_many_lines() {
+        with_device(Device::NanoS, || {
+            // Lines are stacked without padding and overflow the screen
+            assert_eq!(line_y(Location::Middle, false, 10, 0), 0);
+            assert_eq!(line_y(Location::Middle, false, 10, 9), 108);
+        });
+    }
+
+    #[test]
+    fn oversized_label_width() {
+        let labels = [
+            Label::from("short"),
+            Label::from("This label is far too long to fit"),
+        ];
+        assert!(labels.compute_width(false) > 128);
+        assert_eq!(labels[..0].compute_width(false), 0);
+    }
+}
@@ -1,55 +1,123 @@
 #![allow(dead_code)]
 
-use ledger_sdk_sys::{seph, buttons::{get_button_event, ButtonEvent, ButtonsState}};
+use ledger_sdk_sys::{
+    buttons::{get_button_event, ButtonsState},
+    seph,
+};
 
 use crate::bagls::*;
 
-use crate::layout;
+use crate::bitmaps::Glyph;
+use crate::event::{
+    packet_len, AutoRepeat, Button, Event, EventSource, InputGuard, TimedOut, Timeout,
+    SEPH_PACKET_LEN,
+};
+use crate::executor::Events;
 use crate::layout::{Draw, Location, StringPlace};
+use crate::screen::ScreenProfile;
 
-/// Handles communication to filter
-/// out actual events, and converts key
-/// events into presses/releases
-pub fn get_event(buttons: &mut ButtonsState) -> Option<ButtonEvent> {
+#[cfg(target_os = "nanos")]
+use crate::string_mcu::{line_y, place_at};
+#[cfg(not(target_os = "nanos"))]
+use crate::string_se::{line_y, place_at};
+
+/// Height of a line of regular text
+fn line_height() -> usize {
+    ScreenProfile::current().text_font(false).height as usize
+}
+
+/// Handles communication with the SE proxy
+/// and converts key events into presses/releases.
+/// Other events (ticker, APDU, USB) are returned as well
+/// so that the caller can decide what to do with them.
+/// The whole SEPH packet is received in `packet`, truncated
+/// to its length, so that APDUs and USB transfers can be
+/// handed to the IO layer of the app.
+pub fn get_event(buttons: &mut ButtonsState, packet: &mut [u8]) -> Option<Event> {
     if !seph::is_status_sent() {
         seph::send_general_status();
     }
 
-    // TODO: Receiving an APDU while in UX will lead to .. exit ?
     while seph::is_status_sent() {
-        seph::seph_recv(&mut buttons.cmd_buffer, 0);
-        let tag = buttons.cmd_buffer[0];
-
-        // button push event
-        if tag == 0x05 {
-            let button_info = buttons.cmd_buffer[3] >> 1;
-            return get_button_event(buttons, button_info);
+        seph::seph_recv(packet, 0);
+        let event = match crate::event::decode(packet) {
+            Some(event) => Some(event),
+            // button push event
+            None => {
+                let button_info = packet.get(3).copied().unwrap_or(0) >> 1;
+                get_button_event(buttons, button_info).map(Event::from)
+            }
+        };
+        if event.is_some() {
+            return event;
         }
     }
     None
 }
 
+/// Events received from the SE proxy, see `get_event`
+pub struct SephEvents {
+    buttons: ButtonsState,
+    packet: [u8; SEPH_PACKET_LEN],
+}
+
+impl SephEvents {
+    pub fn new() -> Self {
+        SephEvents {
+            buttons: ButtonsState::new(),
+            packet: [0; SEPH_PACKET_LEN],
+        }
+    }
+
+    /// SEPH packet of the last event, header included.
+    /// Holds the command after an `Event::Apdu`, or the
+    /// transfer to hand to the USB stack after an `Event::UsbTransfer`.
+    pub fn packet(&self) -> &[u8] {
+        &self.packet[..packet_len(&self.packet)]
+    }
+}
+
+impl Default for SephEvents {
+    fn default() -> Self {
+        SephEvents::new()
+    }
+}
+
+impl EventSource for SephEvents {
+    /// Wait for the next event. Never runs out.
+    fn next_event(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = get_event(&mut self.buttons, &mut self.packet) {
+                return Some(event);
+            }
+        }
+    }
+}
+
 pub fn clear_screen() {
     #[cfg(not(target_os = "nanos"))]
     {
-
         #[cfg(not(feature = "speculos"))]
-        unsafe {
-            ledger_sdk_sys::screen_clear();
-        }
+        crate::screen_util::screen_clear();
 
         #[cfg(feature = "speculos")]
         {
             // Speculos does not emulate the screen_clear syscall yet
+            let profile = ScreenProfile::current();
             RectFull::new()
-                .width(crate::SCREEN_WIDTH as u32)
-                .height(crate::SCREEN_HEIGHT as u32)
+                .width(profile.width as u32)
+                .height(profile.height as u32)
                 .erase();
         }
     }
 
     #[cfg(target_os = "nanos")]
-    BLANK.paint();
+    {
+        let profile = ScreenProfile::current();
+        BLANK
+            .dims(profile.width as u16, profile.height as u16)
+            .paint();
+    }
 }
 
 /// Shorthand to display a single message
@@ -59,74 +127,172 @@ pub fn popup(message: &str) {
     SingleMessage::new(&message).show_and_wait();
 }
 
+/// A gadget as a state machine, so that the caller
+/// can own the event loop and interleave it with
+/// APDU handling. The blocking methods of the gadgets
+/// are thin loops over `render` and `handle`.
+pub trait Gadget {
+    type Outcome;
+
+    /// Draw the whole gadget in its current state
+    fn render(&self);
+
+    /// Update the gadget with `event`, redrawing what changed.
+    /// Returns the outcome once the user is done.
+    fn handle(&mut self, event: Event) -> Option<Self::Outcome>;
+
+    /// Outcome to return right away, without rendering,
+    /// e.g. when there is nothing to display
+    fn immediate(&self) -> Option<Self::Outcome> {
+        None
+    }
+}
+
+/// Ways of running any gadget, besides its own blocking method
+// Gadgets are not required to be `Send`: apps run on a single thread
+#[allow(async_fn_in_trait)]
+pub trait GadgetExt: Gadget + Sized {
+    /// Run the gadget, reading events from `source`.
+    /// Returns `None` if `source` runs out of events first.
+    fn show_with(mut self, source: &mut impl EventSource) -> Option<Self::Outcome> {
+        run(&mut self, source, Timeout::never()).unwrap_or(None)
+    }
+
+    /// Run the gadget, giving up after `ms` milliseconds
+    /// without any button activity
+    fn show_with_timeout(mut self, ms: u32) -> Result<Self::Outcome, TimedOut> {
+        let mut source = SephEvents::new();
+        loop {
+            // Only reached again if `source` ran out, which it never does
+            if let Some(outcome) = run(&mut self, &mut source, Timeout::new(ms))? {
+                return Ok(outcome);
+            }
+        }
+    }
+
+    /// Run the gadget, awaiting events from `events`
+    async fn show_async(mut self, events: &Events) -> Self::Outcome {
+        run_async(&mut self, events).await
+    }
+}
+
+impl<G: Gadget> GadgetExt for G {}
+
+/// Render `gadget` and feed it events from `source` until it returns
+/// an outcome. Returns `None` if `source` runs out of events first.
+/// Button events left over from the previous screen are ignored,
+/// and do not reset `timeout`.
+pub(crate) fn run<G: Gadget, S: EventSource>(
+    gadget: &mut G,
+    source: &mut S,
+    mut timeout: Timeout,
+) -> Result<Option<G::Outcome>, TimedOut> {
+    if let Some(outcome) = gadget.immediate() {
+        return Ok(Some(outcome));
+    }
+    let mut guard = InputGuard::new();
+    gadget.render();
+    while let Some(event) = source.next_event() {
+        let Some(event) = guard.filter(event) else {
+            continue;
+        };
+        if timeout.expired(event) {
+            return Err(TimedOut);
+        }
+        if let Some(outcome) = gadget.handle(event) {
+            return Ok(Some(outcome));
+        }
+    }
+    Ok(None)
+}
+
+/// Render `gadget` and feed it events until it returns an outcome.
+/// APDU and USB events are left to the other futures.
+/// Button events left over from the previous screen are ignored.
+pub(crate) async fn run_async<G: Gadget>(gadget: &mut G, events: &Events) -> G::Outcome {
+    if let Some(outcome) = gadget.immediate() {
+        return outcome;
+    }
+    let mut guard = InputGuard::new();
+    gadget.render();
+    loop {
+        let event = events
+            .next_where(|e| !matches!(e, Event::Apdu | Event::UsbTransfer | Event::Usb(_)))
+            .await;
+        if let Some(outcome) = guard.filter(event).and_then(|e| gadget.handle(e)) {
+            return outcome;
+        }
+    }
+}
+
 /// Display a single screen with a message,
 /// and exit the function with 'true'
 /// if the user validated 'message'
 /// or false if the user aborted
+#[derive(Copy, Clone)]
 pub struct Validator<'a> {
     message: &'a str,
+    response: bool,
 }
 
 impl<'a> Validator<'a> {
     pub fn new(message: &'a str) -> Self {
-        Validator { message }
+        Validator {
+            message,
+            response: false,
+        }
     }
 
     pub fn ask(&self) -> bool {
-        clear_screen();
-        let mut buttons = ButtonsState::new();
-
-        let mut lines = [Label::from_const("Cancel"), Label::from(self.message)];
-
-        lines[0].bold = true;
+        self.show_with(&mut SephEvents::new()).unwrap_or(false)
+    }
+}
 
-        let redraw = |lines_list: &[Label; 2]| {
-            clear_screen();
-            lines_list.place(Location::Middle, Layout::Centered, false);
+impl Gadget for Validator<'_> {
+    type Outcome = bool;
 
-            UP_ARROW.display();
-            DOWN_ARROW.display();
+    fn render(&self) {
+        clear_screen();
+        let mut lines = [Label::from_const("Cancel"), Label::from(self.message)];
+        lines[self.response as usize].bold = true;
+        lines.place(Location::Middle, Layout::Centered, false);
 
-            crate::screen_util::screen_update();
-        };
-        redraw(&lines);
+        Arrow::Up.icon().display();
+        Arrow::Down.icon().display();
 
-        let mut response = false;
+        crate::screen_util::screen_update();
+    }
 
-        loop {
-            match get_event(&mut buttons) {
-                Some(ButtonEvent::LeftButtonPress) => {
-                    UP_S_ARROW.instant_display();
-                }
-                Some(ButtonEvent::RightButtonPress) => {
-                    DOWN_S_ARROW.instant_display();
-                }
-                Some(ButtonEvent::LeftButtonRelease) => {
-                    UP_S_ARROW.erase();
-                    response = false;
-                    lines[0].bold = true;
-                    lines[1].bold = false;
-                    lines.place(Location::Middle, Layout::Centered, false);
-                    redraw(&lines);
-                }
-                Some(ButtonEvent::RightButtonRelease) => {
-                    DOWN_S_ARROW.erase();
-                    response = true;
-                    lines[0].bold = false;
-                    lines[1].bold = true;
-                    redraw(&lines);
-                }
-                Some(ButtonEvent::BothButtonsPress) => {
-                    UP_ARROW.erase();
-                    DOWN_ARROW.erase();
-                }
-                Some(ButtonEvent::BothButtonsRelease) => return response,
-                _ => (),
+    fn handle(&mut self, event: Event) -> Option<bool> {
+        match event {
+            Event::Press(Button::Left) => {
+                Arrow::Up.pressed().instant_display();
+            }
+            Event::Press(Button::Right) => {
+                Arrow::Down.pressed().instant_display();
+            }
+            Event::Release(Button::Left) => {
+                Arrow::Up.pressed().erase();
+                self.response = false;
+                self.render();
+            }
+            Event::Release(Button::Right) => {
+                Arrow::Down.pressed().erase();
+                self.response = true;
+                self.render();
             }
+            Event::Press(Button::Both) => {
+                Arrow::Up.icon().erase();
+                Arrow::Down.icon().erase();
+            }
+            Event::Release(Button::Both) => return Some(self.response),
+            _ => (),
         }
+        None
     }
 }
 
+#[derive(Copy, Clone)]
 pub struct MessageValidator<'a> {
     /// Strings displayed in the pages. One string per page. Can be empty.
     message: &'a [&'a str],
@@ -140,9 +306,53 @@ pub struct MessageValidator<'a> {
     /// 1 element: icon and one line of text displayed.
     /// 2 elements: icon and two lines of text displayed.
     cancel: &'a [&'a str],
+    repeat: AutoRepeat,
+    page: usize,
+    /// Set once the held button already changed pages,
+    /// so that its release does not change them again
+    held: bool,
 }
 
 use crate::layout::*;
+/// Spacing in pixels between the icon and the text
+/// of the confirmation and cancel pages
+const ICON_TEXT_GAP: usize = 6;
+
+/// `dy` shifts the text down, the icon being positioned by the caller
+fn draw_icon_and_text(icon: Option<Icon>, strings: &[&str], dy: i16) {
+    let strings = &strings[..strings.len().min(2)];
+    let (icon_width, gap) = match &icon {
+        Some(icon) => (icon.icon.width as usize, ICON_TEXT_GAP),
+        None => (0, 0),
+    };
+    // Draw icon on the center if there is no text.
+    if strings.is_empty() {
+        if let Some(icon) = icon {
+            icon.set_x(Layout::Centered.get_x(icon_width) as i16)
+                .display();
+        }
+        return;
+    }
+    // Otherwise the text is shifted right to make room
+    // for the icon, which sits left of the widest line
+    let font = ScreenProfile::current().text_font(false);
+    let text_width = strings.iter().fold(0, |acc, s| acc.max(font.text_width(s)));
+    if let Some(icon) = icon {
+        let icon_x = Layout::Centered.get_x(text_width + icon_width + gap);
+        icon.set_x(icon_x as i16).display();
+    }
+    let shift = ((icon_width + gap) / 2) as i16;
+    let total_height = strings.len() * (line_height() + crate::PADDING);
+    let mut y = (Location::Middle.get_y(total_height) + crate::PADDING / 2)
+        .saturating_add_signed(dy as isize);
+    for s in strings {
+        Label::from(*s)
+            .location(Location::Custom(y))
+            .offset(shift, 0)
+            .display();
+        y += line_height() + crate::PADDING;
+    }
+}
 
 impl<'a> MessageValidator<'a> {
     pub const fn new(
@@ -154,153 +364,610 @@ impl<'a> MessageValidator<'a> {
             message,
             confirm,
             cancel,
+            repeat: AutoRepeat::new(),
+            page: 0,
+            held: false,
         }
     }
 
+    /// Change pages automatically while a button is held
+    pub const fn auto_repeat(self, repeat: AutoRepeat) -> Self {
+        MessageValidator { repeat, ..self }
+    }
+
     pub fn ask(&self) -> bool {
+        self.show_with(&mut SephEvents::new()).unwrap_or(false)
+    }
+
+    fn page_count(&self) -> usize {
+        self.message.len() + 2
+    }
+}
+
+impl Gadget for MessageValidator<'_> {
+    type Outcome = bool;
+
+    fn render(&self) {
         clear_screen();
-        let page_count = &self.message.len() + 2;
-        let mut cur_page = 0;
-
-        let draw_icon_and_text = |icon: Icon, strings: &[&str]| {
-            // Draw icon on the center if there is no text.
-            let x = match strings.len() {
-                0 => 60,
-                _ => 18,
-            };
-            icon.set_x(x).display();
-            match strings.len() {
-                0 => {}
-                1 => {
-                    strings[0].place(Location::Middle, Layout::Centered, false);
+        let page_count = self.page_count();
+        if self.page == page_count - 2 {
+            draw_icon_and_text(Some(CHECKMARK_ICON), self.confirm, 0);
+            Arrow::Right.icon().display();
+        } else if self.page == page_count - 1 {
+            draw_icon_and_text(Some(CROSS_ICON), self.cancel, 0);
+        } else {
+            self.message[self.page].place(Location::Middle, Layout::Centered, false);
+            Arrow::Right.icon().display();
+        }
+        if self.page > 0 {
+            Arrow::Left.icon().display();
+        }
+        crate::screen_util::screen_update();
+    }
+
+    fn handle(&mut self, event: Event) -> Option<bool> {
+        let page_count = self.page_count();
+        match self.repeat.filter(event) {
+            Event::Press(Button::Left) => {
+                self.held = false;
+                Arrow::Left.pressed().instant_display();
+            }
+            Event::Press(Button::Right) => {
+                self.held = false;
+                Arrow::Right.pressed().instant_display();
+            }
+            Event::LongPress(Button::Left) | Event::Repeat(Button::Left) => {
+                self.held = true;
+                self.page = self.page.saturating_sub(1);
+                self.render();
+                Arrow::Left.pressed().instant_display();
+            }
+            Event::LongPress(Button::Right) | Event::Repeat(Button::Right) => {
+                self.held = true;
+                if self.page < page_count - 1 {
+                    self.page += 1;
                 }
-                _ => {
-                    strings[..2].place(Location::Middle, Layout::Centered, false);
+                self.render();
+                Arrow::Right.pressed().instant_display();
+            }
+            Event::Release(Button::Left) => {
+                Arrow::Left.pressed().erase();
+                if self.page > 0 && !self.held {
+                    self.page -= 1;
                 }
+                self.render();
             }
-        };
+            Event::Release(Button::Right) => {
+                Arrow::Right.pressed().erase();
+                if self.page < page_count - 1 && !self.held {
+                    self.page += 1;
+                }
+                self.render();
+            }
+            Event::Release(Button::Both) => {
+                if self.page == page_count - 2 {
+                    // Confirm
+                    return Some(true);
+                } else if self.page == page_count - 1 {
+                    // Abort
+                    return Some(false);
+                }
+                self.render();
+            }
+            _ => (),
+        }
+        None
+    }
+}
 
-        let draw = |page: usize| {
-            clear_screen();
-            if page == page_count - 2 {
-                draw_icon_and_text(CHECKMARK_ICON, &self.confirm);
-                RIGHT_ARROW.display();
-            } else if page == page_count - 1 {
-                draw_icon_and_text(CROSS_ICON, &self.cancel);
-            } else {
-                self.message[page].place(Location::Middle, Layout::Centered, false);
-                RIGHT_ARROW.display();
-            }
-            if page > 0 {
-                LEFT_ARROW.display();
-            }
-            crate::screen_util::screen_update();
-        };
+/// How a menu entry behaves
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ItemKind {
+    Selectable,
+    /// Section title, skipped by the cursor and underlined
+    Header,
+    /// Skipped by the cursor and struck through
+    Disabled,
+}
+
+/// A menu entry with an optional icon on the left
+/// of one or two lines of text, as on the dashboard.
+/// Entries which are not selectable are shown as a single
+/// line of text above or below the selected entry.
+#[derive(Copy, Clone)]
+pub struct MenuItem<'a> {
+    icon: Option<&'a Glyph<'a>>,
+    lines: [&'a str; 2],
+    line_count: usize,
+    kind: ItemKind,
+}
+
+impl<'a> MenuItem<'a> {
+    pub const fn new(text: &'a str) -> Self {
+        MenuItem {
+            icon: None,
+            lines: [text, ""],
+            line_count: 1,
+            kind: ItemKind::Selectable,
+        }
+    }
+
+    pub const fn kind(self, kind: ItemKind) -> Self {
+        MenuItem { kind, ..self }
+    }
+
+    pub const fn icon(self, icon: &'a Glyph<'a>) -> Self {
+        MenuItem {
+            icon: Some(icon),
+            ..self
+        }
+    }
+
+    /// Add a line of text below the first one
+    pub const fn second_line(self, text: &'a str) -> Self {
+        MenuItem {
+            lines: [self.lines[0], text],
+            line_count: 2,
+            ..self
+        }
+    }
+
+    /// Draw the entry centered, shifted down by `dy` pixels
+    pub(crate) fn draw(&self, dy: i16) {
+        let icon = self.icon.map(|icon| Icon::from(icon).shift_v(dy));
+        draw_icon_and_text(icon, &self.lines[..self.line_count], dy);
+    }
+
+    /// First line of the entry, without its icon
+    fn label(&self) -> Label<'a> {
+        Label::from(self.lines[0])
+    }
+}
 
-        draw(cur_page);
+fn draw_row(label: &Label, kind: ItemKind) {
+    label.display();
+    draw_style(label, kind);
+}
+
+/// Underline `label` if it is a header, strike it through
+/// if it is disabled, once it is displayed
+fn draw_style(label: &Label, kind: ItemKind) {
+    let area = label.bounds();
+    if area.width == 0 {
+        return;
+    }
+    let row = match kind {
+        ItemKind::Selectable => return,
+        ItemKind::Header => area.height.saturating_sub(1),
+        ItemKind::Disabled => area.height / 2,
+    };
+    RectFull::new()
+        .pos(area.x, area.y + row as i32)
+        .width(area.width as u32)
+        .height(1)
+        .display();
+}
+
+/// Size of the buffer a `MenuSource` writes an entry into
+pub const ITEM_BUF_LEN: usize = 32;
+
+/// Menu entries produced as the user scrolls,
+/// for long or computed lists
+pub trait MenuSource {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Text of entry `index`, either written into `buf` or borrowed from `self`
+    fn item<'b>(&'b self, index: usize, buf: &'b mut [u8; ITEM_BUF_LEN]) -> &'b str;
+
+    fn kind(&self, _index: usize) -> ItemKind {
+        ItemKind::Selectable
+    }
+}
 
-        let mut buttons = ButtonsState::new();
+/// Fixed entries, all selectable
+impl MenuSource for [&str] {
+    fn len(&self) -> usize {
+        <[&str]>::len(self)
+    }
+
+    fn item<'b>(&'b self, index: usize, _buf: &'b mut [u8; ITEM_BUF_LEN]) -> &'b str {
+        self.get(index).copied().unwrap_or("")
+    }
+}
+
+/// Fixed entries with their kinds
+impl<const N: usize> MenuSource for [(&str, ItemKind); N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn item<'b>(&'b self, index: usize, _buf: &'b mut [u8; ITEM_BUF_LEN]) -> &'b str {
+        self.get(index).map_or("", |entry| entry.0)
+    }
+
+    fn kind(&self, index: usize) -> ItemKind {
+        self.get(index)
+            .map_or(ItemKind::Selectable, |entry| entry.1)
+    }
+}
+
+/// Writes text and numbers into a buffer, truncated to its length,
+/// to implement `MenuSource::item`.
+/// Used instead of `core::fmt`, see `crate::pic`.
+pub struct ItemWriter<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl<'b> ItemWriter<'b> {
+    pub fn new(buf: &'b mut [u8]) -> Self {
+        ItemWriter { buf, len: 0 }
+    }
+
+    pub fn str(mut self, text: &str) -> Self {
+        let n = text.len().min(self.buf.len() - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&text.as_bytes()[..n]);
+        self.len += n;
+        self
+    }
+
+    /// Decimal digits of `n`
+    pub fn num(self, n: usize) -> Self {
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        let mut n = n;
         loop {
-            match get_event(&mut buttons) {
-                Some(ButtonEvent::LeftButtonPress) => {
-                    LEFT_S_ARROW.instant_display();
-                }
-                Some(ButtonEvent::RightButtonPress) => {
-                    RIGHT_S_ARROW.instant_display();
-                }
-                Some(ButtonEvent::LeftButtonRelease) => {
-                    LEFT_S_ARROW.erase();
-                    if cur_page > 0 {
-                        cur_page -= 1;
-                    }
-                    draw(cur_page);
-                }
-                Some(ButtonEvent::RightButtonRelease) => {
-                    RIGHT_S_ARROW.erase();
-                    if cur_page < page_count - 1 {
-                        cur_page += 1;
-                    }
-                    draw(cur_page);
-                }
-                Some(ButtonEvent::BothButtonsRelease) => {
-                    if cur_page == page_count - 2 {
-                        // Confirm
-                        return true;
-                    } else if cur_page == page_count - 1 {
-                        // Abort
-                        return false;
-                    }
-                    draw(cur_page);
-                }
-                _ => (),
+            start -= 1;
+            digits[start] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
             }
         }
+        self.str(core::str::from_utf8(&digits[start..]).unwrap_or(""))
     }
+
+    /// Text written so far
+    pub fn finish(self) -> &'b str {
+        let ItemWriter { buf, len } = self;
+        // Truncation may have split a character
+        match core::str::from_utf8(&buf[..len]) {
+            Ok(text) => text,
+            Err(e) => core::str::from_utf8(&buf[..e.valid_up_to()]).unwrap_or(""),
+        }
+    }
+}
+
+enum Entries<'a, S: ?Sized> {
+    /// Several centered lines per screen
+    Text(&'a [&'a str]),
+    /// Same as `Text`, produced on demand
+    Source(&'a S),
+    /// One item per screen
+    Items(&'a [MenuItem<'a>]),
+}
+
+/// The source type `S` is only used by `Menu::from_source`.
+/// Entries are read through it directly rather than through
+/// a `dyn MenuSource`, see `crate::pic`.
+pub struct Menu<'a, S: ?Sized = [&'a str]> {
+    entries: Entries<'a, S>,
+    repeat: AutoRepeat,
+    index: usize,
+    /// Set once the held button already scrolled,
+    /// so that its release does not scroll again
+    held: bool,
+    wrap: bool,
+    sliding: bool,
+    indicator: bool,
 }
 
-pub struct Menu<'a> {
-    panels: &'a [&'a str],
+// Derives would require `S: Copy`
+impl<S: ?Sized> Clone for Entries<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: ?Sized> Copy for Entries<'_, S> {}
+
+impl<S: ?Sized> Clone for Menu<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
+impl<S: ?Sized> Copy for Menu<'_, S> {}
+
 impl<'a> Menu<'a> {
     pub fn new(panels: &'a [&'a str]) -> Self {
-        Menu { panels }
+        Menu::with_entries(Entries::Text(panels))
+    }
+
+    /// Menu showing one item at a time, with its icon
+    pub const fn from_items(items: &'a [MenuItem<'a>]) -> Self {
+        Menu::with_entries(Entries::Items(items))
+    }
+}
+
+impl<'a, S: MenuSource + ?Sized> Menu<'a, S> {
+    /// Menu reading its entries from `source` as they are displayed
+    pub fn from_source(source: &'a S) -> Self {
+        Menu::with_entries(Entries::Source(source))
+    }
+
+    /// Same menu, cursor included, reading its entries from `source`.
+    /// Lets a gadget keep the menu of a source it rebuilds.
+    pub(crate) fn with_source<'b>(self, source: &'b S) -> Menu<'b, S> {
+        Menu {
+            entries: Entries::Source(source),
+            repeat: self.repeat,
+            index: self.index,
+            held: self.held,
+            wrap: self.wrap,
+            sliding: self.sliding,
+            indicator: self.indicator,
+        }
+    }
+
+    const fn with_entries(entries: Entries<'a, S>) -> Self {
+        Menu {
+            entries,
+            repeat: AutoRepeat::new(),
+            index: 0,
+            held: false,
+            wrap: false,
+            sliding: false,
+            indicator: false,
+        }
+    }
+
+    /// Scroll automatically while a button is held
+    pub const fn auto_repeat(self, repeat: AutoRepeat) -> Self {
+        Menu { repeat, ..self }
+    }
+
+    /// Select entry `index` when shown
+    pub const fn initial(self, index: usize) -> Self {
+        Menu { index, ..self }
+    }
+
+    /// Go from the last entry to the first one and back
+    pub const fn wrap(self, wrap: bool) -> Self {
+        Menu { wrap, ..self }
+    }
+
+    /// Scroll entries one by one, keeping the selection centered,
+    /// instead of by pages of `max_lines` entries
+    pub const fn sliding_window(self, sliding: bool) -> Self {
+        Menu { sliding, ..self }
+    }
+
+    /// Show the position in the list on the right edge of the screen
+    pub const fn scroll_indicator(self, indicator: bool) -> Self {
+        Menu { indicator, ..self }
     }
 
     pub fn show(&self) -> usize {
-        clear_screen();
-        let mut buttons = ButtonsState::new();
+        self.show_with(&mut SephEvents::new()).unwrap_or(0)
+    }
 
-        let mut items: [Label; layout::MAX_LINES] =
-            core::array::from_fn(|i| Label::from(*self.panels.get(i).unwrap_or(&"")));
+    fn len(&self) -> usize {
+        match self.entries {
+            Entries::Text(panels) => panels.len(),
+            Entries::Source(source) => source.len(),
+            Entries::Items(items) => items.len(),
+        }
+    }
+
+    /// Arrows matching the left and right buttons
+    fn arrows(&self) -> (Arrow, Arrow) {
+        match self.entries {
+            Entries::Text(_) | Entries::Source(_) => (Arrow::Up, Arrow::Down),
+            Entries::Items(_) => (Arrow::Left, Arrow::Right),
+        }
+    }
 
-        items[0].bold = true;
-        items.place(Location::Middle, Layout::Centered, false);
+    fn kind_of(&self, index: usize) -> ItemKind {
+        match self.entries {
+            Entries::Text(_) => ItemKind::Selectable,
+            Entries::Source(source) => source.kind(index),
+            Entries::Items(items) => items.get(index).map_or(ItemKind::Selectable, |i| i.kind),
+        }
+    }
 
-        UP_ARROW.display();
-        DOWN_ARROW.display();
+    fn is_selectable(&self, index: usize) -> bool {
+        self.kind_of(index) == ItemKind::Selectable
+    }
 
-        crate::screen_util::screen_update();
+    /// Selected entry: `index`, or the closest selectable entry after it,
+    /// or before it if there is none after.
+    /// `None` if no entry is selectable.
+    fn current(&self) -> Option<usize> {
+        let len = self.len();
+        (self.index..len)
+            .chain((0..self.index.min(len)).rev())
+            .find(|&i| self.is_selectable(i))
+    }
 
-        let mut index = 0;
+    /// Entry the screen is laid out around
+    fn position(&self) -> usize {
+        self.current().unwrap_or(self.index)
+    }
 
-        loop {
-            match get_event(&mut buttons) {
-                Some(ButtonEvent::LeftButtonPress) => {
-                    UP_S_ARROW.instant_display();
+    /// Next selectable entry in the direction of `button`
+    fn step(&self, button: Button) -> usize {
+        let len = self.len();
+        let current = self.position();
+        let mut index = current;
+        for _ in 0..len {
+            index = match button {
+                Button::Left if index > 0 => index - 1,
+                Button::Left if self.wrap && len > 0 => len - 1,
+                Button::Right if index + 1 < len => index + 1,
+                Button::Right if self.wrap => 0,
+                _ => return current,
+            };
+            if self.is_selectable(index) {
+                return index;
+            }
+        }
+        current
+    }
+
+    /// Index of the first of `visible` entries on screen
+    fn first_visible(&self, visible: usize) -> usize {
+        let current = self.position();
+        if self.sliding {
+            let last_first = self.len().saturating_sub(visible);
+            current.saturating_sub(visible / 2).min(last_first)
+        } else {
+            (current / visible) * visible
+        }
+    }
+
+    /// Underline headers and strike disabled entries through,
+    /// once `labels` are placed
+    fn draw_styles(&self, labels: &[Label], first: usize) {
+        for (i, label) in labels.iter().enumerate() {
+            let y = line_y(Location::Middle, false, labels.len(), i);
+            let mut placed = Label::from(label.text).location(Location::Custom(y));
+            placed.bold = label.bold;
+            draw_style(&placed, self.kind_of(first + i));
+        }
+    }
+
+    /// Draw item `current` of `items`, along with the entries next to it
+    /// which are not selectable, so that headers and disabled entries
+    /// are seen although the cursor skips them
+    fn draw_item(&self, items: &[MenuItem], current: usize) {
+        let neighbour = |index: Option<usize>| {
+            index
+                .and_then(|i| items.get(i))
+                .filter(|item| item.kind != ItemKind::Selectable)
+        };
+        let above = neighbour(current.checked_sub(1));
+        let mut below = neighbour(current.checked_add(1));
+        if above.is_some() && ScreenProfile::current().max_lines < 3 {
+            // No room for both on Nano S
+            below = None;
+        }
+        let row = (line_height() + crate::PADDING) as i16;
+        let shift = row / 2 * (above.is_some() as i16 - below.is_some() as i16);
+        if let Some(item) = items.get(current) {
+            match item.kind {
+                ItemKind::Selectable => item.draw(shift),
+                // Nothing is selectable
+                kind => draw_row(&item.label().location(Location::Middle), kind),
+            }
+        }
+        if let Some(item) = above {
+            draw_row(&item.label().location(Location::Top), item.kind);
+        }
+        if let Some(item) = below {
+            draw_row(&item.label().location(Location::Bottom), item.kind);
+        }
+    }
+
+    fn draw_scroll_indicator(&self, first: usize, visible: usize) {
+        let len = self.len();
+        if !self.indicator || len <= visible {
+            return;
+        }
+        let profile = ScreenProfile::current();
+        let thumb = (profile.height * visible / len).max(2);
+        // The last page may start after `len - visible`
+        let first = first.min(len - visible);
+        let y = (profile.height - thumb) * first / (len - visible);
+        RectFull::new()
+            .pos(profile.width as i32 - 1, y as i32)
+            .width(1)
+            .height(thumb as u32)
+            .display();
+    }
+}
+
+impl<S: MenuSource + ?Sized> Gadget for Menu<'_, S> {
+    type Outcome = usize;
+
+    fn render(&self) {
+        clear_screen();
+        match self.entries {
+            Entries::Text(_) | Entries::Source(_) => {
+                Arrow::Up.icon().display();
+                Arrow::Down.icon().display();
+
+                let max_lines = ScreenProfile::current().max_lines;
+                let first = self.first_visible(max_lines);
+                let mut bufs = [[0u8; ITEM_BUF_LEN]; ScreenProfile::MAX_LINES];
+                let mut texts = [""; ScreenProfile::MAX_LINES];
+                for (i, (buf, text)) in bufs.iter_mut().zip(texts.iter_mut()).enumerate() {
+                    *text = match self.entries {
+                        Entries::Text(panels) => panels.get(first + i).copied().unwrap_or(""),
+                        Entries::Source(source) if first + i < source.len() => {
+                            source.item(first + i, buf)
+                        }
+                        _ => "",
+                    };
                 }
-                Some(ButtonEvent::RightButtonPress) => {
-                    DOWN_S_ARROW.instant_display();
+                let mut items: [Label; ScreenProfile::MAX_LINES] =
+                    core::array::from_fn(|i| Label::from(texts[i]));
+                if let Some(item) = self.current().and_then(|c| items.get_mut(c - first)) {
+                    item.bold = true;
                 }
-                Some(ButtonEvent::BothButtonsRelease) => return index,
-                Some(x) => {
-                    match x {
-                        ButtonEvent::LeftButtonRelease => {
-                            index = index.saturating_sub(1);
-                        }
-                        ButtonEvent::RightButtonRelease => {
-                            if index < self.panels.len() - 1 {
-                                index += 1;
-                            }
-                        }
-                        _ => (),
-                    }
-                    clear_screen();
-                    UP_ARROW.display();
-                    DOWN_ARROW.display();
-
-                    let chunk = (index / layout::MAX_LINES) * layout::MAX_LINES;
-                    for (i, item) in items.iter_mut().enumerate() {
-                        item.text = self.panels.get(chunk + i).unwrap_or(&"");
-                        item.bold = false;
-                    }
-                    items[index - chunk].bold = true;
-                    items.place(Location::Middle, Layout::Centered, false);
-                    crate::screen_util::screen_update();
+                items[..max_lines].place(Location::Middle, Layout::Centered, false);
+                self.draw_styles(&items[..max_lines], first);
+                self.draw_scroll_indicator(first, max_lines);
+            }
+            Entries::Items(items) => {
+                let current = self.position();
+                self.draw_item(items, current);
+                if self.step(Button::Left) != current {
+                    Arrow::Left.icon().display();
+                }
+                if self.step(Button::Right) != current {
+                    Arrow::Right.icon().display();
+                }
+                self.draw_scroll_indicator(current, 1);
+            }
+        }
+        crate::screen_util::screen_update();
+    }
+
+    fn handle(&mut self, event: Event) -> Option<usize> {
+        let (left, right) = self.arrows();
+        match self.repeat.filter(event) {
+            Event::Press(Button::Left) => {
+                self.held = false;
+                left.pressed().instant_display();
+            }
+            Event::Press(Button::Right) => {
+                self.held = false;
+                right.pressed().instant_display();
+            }
+            Event::Release(Button::Both) => return self.current(),
+            Event::LongPress(button) | Event::Repeat(button) if button != Button::Both => {
+                self.held = true;
+                self.index = self.step(button);
+                self.render();
+                match button {
+                    Button::Left => left.pressed().instant_display(),
+                    _ => right.pressed().instant_display(),
+                }
+            }
+            Event::Release(button) => {
+                if !self.held {
+                    self.index = self.step(button);
                 }
-                _ => (),
+                self.render();
             }
+            _ => (),
         }
+        None
     }
 }
 
@@ -308,6 +975,7 @@ impl<'a> Menu<'a> {
 /// a short message in the
 /// middle of the screen and
 /// waits for a button press
+#[derive(Copy, Clone)]
 pub struct SingleMessage<'a> {
     message: &'a str,
 }
@@ -325,17 +993,21 @@ impl<'a> SingleMessage<'a> {
     /// Display the message and wait
     /// for any kind of button release
     pub fn show_and_wait(&self) {
-        let mut buttons = ButtonsState::new();
+        self.show_with(&mut SephEvents::new());
+    }
+}
+
+impl Gadget for SingleMessage<'_> {
+    type Outcome = ();
 
+    fn render(&self) {
         self.show();
+    }
 
-        loop {
-            match get_event(&mut buttons) {
-                Some(ButtonEvent::LeftButtonRelease)
-                | Some(ButtonEvent::RightButtonRelease)
-                | Some(ButtonEvent::BothButtonsRelease) => return,
-                _ => (),
-            }
+    fn handle(&mut self, event: Event) -> Option<()> {
+        match event {
+            Event::Release(_) => Some(()),
+            _ => None,
         }
     }
 }
@@ -345,74 +1017,496 @@ impl<'a> SingleMessage<'a> {
 /// over several panes in chunks
 /// of CHAR_N characters.
 /// Press both buttons to exit.
+#[derive(Copy, Clone)]
 pub struct MessageScroller<'a> {
     message: &'a str,
+    repeat: AutoRepeat,
+    page: usize,
+    /// Set once the held button already changed pages,
+    /// so that its release does not change them again
+    held: bool,
 }
 
 impl<'a> MessageScroller<'a> {
     pub fn new(message: &'a str) -> Self {
-        MessageScroller { message }
+        MessageScroller {
+            message,
+            repeat: AutoRepeat::new(),
+            page: 0,
+            held: false,
+        }
+    }
+
+    /// Change pages automatically while a button is held
+    pub const fn auto_repeat(self, repeat: AutoRepeat) -> Self {
+        MessageScroller { repeat, ..self }
     }
 
     pub fn event_loop(&self) {
+        self.show_with(&mut SephEvents::new());
+    }
+
+    const CHAR_N: usize = 16;
+
+    fn page_count(&self) -> usize {
+        self.message.len().div_ceil(Self::CHAR_N)
+    }
+}
+
+impl Gadget for MessageScroller<'_> {
+    type Outcome = ();
+
+    /// Nothing to scroll through
+    fn immediate(&self) -> Option<()> {
+        (self.page_count() == 0).then_some(())
+    }
+
+    fn render(&self) {
         clear_screen();
-        let mut buttons = ButtonsState::new();
-        const CHAR_N: usize = 16;
-        let page_count = (self.message.len() - 1) / CHAR_N + 1;
-        if page_count == 0 {
-            return;
+        let start = self.page * Self::CHAR_N;
+        let end = (start + Self::CHAR_N).min(self.message.len());
+        let chunk = self.message.get(start..end).unwrap_or("");
+        if self.page > 0 {
+            Arrow::Left.icon().display();
         }
-        let mut label = Label::from("");
-        let mut cur_page = 0;
-
-        // A closure to draw common elements of the screen
-        // cur_page passed as parameter to prevent borrowing
-        let mut draw = |page: usize| {
-            let start = page * CHAR_N;
-            let end = (start + CHAR_N).min(self.message.len());
-            let chunk = &self.message[start..end];
-            label.erase();
-            label.text = &chunk;
-            LEFT_ARROW.erase();
-            RIGHT_ARROW.erase();
-            if page > 0 {
-                LEFT_ARROW.display();
-            }
-            if page + 1 < page_count {
-                RIGHT_ARROW.display();
-            }
-            label.instant_display();
-        };
-
-        draw(cur_page);
+        if self.page + 1 < self.page_count() {
+            Arrow::Right.icon().display();
+        }
+        Label::from(chunk).instant_display();
+    }
 
-        loop {
-            match get_event(&mut buttons) {
-                Some(ButtonEvent::LeftButtonPress) => {
-                    LEFT_S_ARROW.instant_display();
+    fn handle(&mut self, event: Event) -> Option<()> {
+        let page_count = self.page_count();
+        match self.repeat.filter(event) {
+            Event::Press(Button::Left) => {
+                self.held = false;
+                Arrow::Left.pressed().instant_display();
+            }
+            Event::Press(Button::Right) => {
+                self.held = false;
+                Arrow::Right.pressed().instant_display();
+            }
+            Event::LongPress(Button::Left) | Event::Repeat(Button::Left) => {
+                self.held = true;
+                self.page = self.page.saturating_sub(1);
+                self.render();
+                Arrow::Left.pressed().instant_display();
+            }
+            Event::LongPress(Button::Right) | Event::Repeat(Button::Right) => {
+                self.held = true;
+                if self.page + 1 < page_count {
+                    self.page += 1;
                 }
-                Some(ButtonEvent::RightButtonPress) => {
-                    RIGHT_S_ARROW.instant_display();
+                self.render();
+                Arrow::Right.pressed().instant_display();
+            }
+            Event::Release(Button::Left) => {
+                if self.page > 0 && !self.held {
+                    self.page -= 1;
                 }
-                Some(ButtonEvent::LeftButtonRelease) => {
-                    LEFT_S_ARROW.erase();
-                    if cur_page > 0 {
-                        cur_page -= 1;
-                    }
-                    // We need to draw anyway to clear button press arrow
-                    draw(cur_page);
+                // We need to draw anyway to clear button press arrow
+                self.render();
+            }
+            Event::Release(Button::Right) => {
+                if self.page + 1 < page_count && !self.held {
+                    self.page += 1;
                 }
-                Some(ButtonEvent::RightButtonRelease) => {
-                    RIGHT_S_ARROW.erase();
-                    if cur_page + 1 < page_count {
-                        cur_page += 1;
-                    }
-                    // We need to draw anyway to clear button press arrow
-                    draw(cur_page);
+                // We need to draw anyway to clear button press arrow
+                self.render();
+            }
+            Event::Release(Button::Both) => return Some(()),
+            _ => (),
+        }
+        None
+    }
+}
+
+/// A paged viewer for addresses and public keys.
+/// The text is split in groups of GROUP_LEN characters
+/// which are flowed over as many lines and pages as needed.
+/// Press both buttons to exit.
+#[derive(Copy, Clone)]
+pub struct AddressDisplay<'a> {
+    address: &'a str,
+    bold_ends: bool,
+    page: usize,
+}
+
+/// Number of characters per group
+const GROUP_LEN: usize = 4;
+/// Spacing in pixels between two groups
+const GROUP_GAP: usize = 3;
+/// Lines per page on the tallest screen
+const MAX_LINES_PER_PAGE: usize = 4;
+
+/// Room left for the groups between the page arrows
+fn groups_width() -> usize {
+    let arrow_width = crate::bitmaps::LEFT_ARROW.width as usize;
+    ScreenProfile::current()
+        .width
+        .saturating_sub(2 * (OUTER_PADDING + arrow_width + crate::PADDING))
+}
+
+fn lines_per_page() -> usize {
+    let height = ScreenProfile::current().height;
+    (height / (line_height() + crate::PADDING)).clamp(1, MAX_LINES_PER_PAGE)
+}
+
+impl<'a> AddressDisplay<'a> {
+    pub const fn new(address: &'a str) -> Self {
+        AddressDisplay {
+            address,
+            bold_ends: false,
+            page: 0,
+        }
+    }
+
+    /// Display the first and last groups in bold,
+    /// for a quick comparison of the checksum characters
+    pub const fn bold_ends(self, bold_ends: bool) -> Self {
+        AddressDisplay { bold_ends, ..self }
+    }
+
+    fn group_count(&self) -> usize {
+        self.address.len().div_ceil(GROUP_LEN)
+    }
+
+    fn group(&self, index: usize) -> &'a str {
+        let start = index * GROUP_LEN;
+        let end = (start + GROUP_LEN).min(self.address.len());
+        self.address.get(start..end).unwrap_or("")
+    }
+
+    fn is_bold(&self, index: usize) -> bool {
+        self.bold_ends && (index == 0 || index + 1 == self.group_count())
+    }
+
+    fn group_width(&self, index: usize) -> usize {
+        let font = ScreenProfile::current().text_font(self.is_bold(index));
+        font.text_width(self.group(index))
+    }
+
+    /// Returns the index following the last group of the line
+    /// starting with group `first`, along with the line width
+    fn line_end(&self, first: usize) -> (usize, usize) {
+        let mut end = first;
+        let mut width = 0;
+        while end < self.group_count() {
+            let gap = if end > first { GROUP_GAP } else { 0 };
+            let w = gap + self.group_width(end);
+            // Always put at least one group on a line
+            if end > first && width + w > groups_width() {
+                break;
+            }
+            width += w;
+            end += 1;
+        }
+        (end, width)
+    }
+
+    /// Index of the first group displayed on `page`
+    fn page_start(&self, page: usize) -> usize {
+        let mut first = 0;
+        for _ in 0..page * lines_per_page() {
+            first = self.line_end(first).0;
+        }
+        first
+    }
+
+    fn page_count(&self) -> usize {
+        let mut lines = 0usize;
+        let mut first = 0;
+        while first < self.group_count() {
+            first = self.line_end(first).0;
+            lines += 1;
+        }
+        lines.div_ceil(lines_per_page())
+    }
+
+    fn draw_page(&self, page: usize, page_count: usize) {
+        clear_screen();
+        let mut first = self.page_start(page);
+        let mut lines = [(0, 0, 0); MAX_LINES_PER_PAGE];
+        let mut line_count = 0;
+        while line_count < lines_per_page() && first < self.group_count() {
+            let (end, width) = self.line_end(first);
+            lines[line_count] = (first, end, width);
+            line_count += 1;
+            first = end;
+        }
+
+        let total_height = line_count * (line_height() + crate::PADDING);
+        let mut y = Location::Middle.get_y(total_height) + crate::PADDING / 2;
+        for &(first, end, width) in lines[..line_count].iter() {
+            let mut x = Layout::Centered.get_x(width);
+            for index in first..end {
+                place_at(self.group(index), x as i32, y as i32, self.is_bold(index));
+                x += self.group_width(index) + GROUP_GAP;
+            }
+            y += line_height() + crate::PADDING;
+        }
+
+        if page > 0 {
+            Arrow::Left.icon().display();
+        }
+        if page + 1 < page_count {
+            Arrow::Right.icon().display();
+        }
+        crate::screen_util::screen_update();
+    }
+
+    pub fn event_loop(&self) {
+        self.show_with(&mut SephEvents::new());
+    }
+}
+
+impl Gadget for AddressDisplay<'_> {
+    type Outcome = ();
+
+    /// Nothing to scroll through
+    fn immediate(&self) -> Option<()> {
+        (self.page_count() == 0).then_some(())
+    }
+
+    fn render(&self) {
+        self.draw_page(self.page, self.page_count());
+    }
+
+    fn handle(&mut self, event: Event) -> Option<()> {
+        match event {
+            Event::Press(Button::Left) => {
+                Arrow::Left.pressed().instant_display();
+            }
+            Event::Press(Button::Right) => {
+                Arrow::Right.pressed().instant_display();
+            }
+            Event::Release(Button::Left) => {
+                self.page = self.page.saturating_sub(1);
+                // Redraw anyway to clear the button press arrow
+                self.render();
+            }
+            Event::Release(Button::Right) => {
+                if self.page + 1 < self.page_count() {
+                    self.page += 1;
                 }
-                Some(ButtonEvent::BothButtonsRelease) => break,
-                Some(_) | None => (),
+                // Redraw anyway to clear the button press arrow
+                self.render();
             }
+            Event::Release(Button::Both) => return Some(()),
+            _ => (),
         }
+        None
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::event::Script;
+    use crate::screen::{with_device, Device};
+    use crate::screen_util::stub;
+
+    pub(crate) const DEVICES: [Device; 3] = [Device::NanoS, Device::NanoX, Device::NanoSPlus];
+
+    /// Render `gadget` on every device, checking
+    /// that nothing is drawn outside of the screen
+    pub(crate) fn assert_fits(gadget: &impl Gadget) {
+        for device in DEVICES {
+            let profile = device.profile();
+            with_device(device, || {
+                stub::take();
+                gadget.render();
+                for area in stub::take() {
+                    let fits = area.x >= 0
+                        && area.y >= 0
+                        && area.x as usize + area.width <= profile.width
+                        && area.y as usize + area.height <= profile.height;
+                    assert!(fits, "{:?} drawn off screen on {:?}", area, device);
+                }
+            });
+        }
+    }
+
+    #[test]
+    fn gadgets_fit_every_device() {
+        assert_fits(&Validator::new("Sign?"));
+        let mut review = MessageValidator::new(&["Review"], &["Confirm", "message?"], &["Cancel"]);
+        for page in 0..review.page_count() {
+            review.page = page;
+            assert_fits(&review);
+        }
+        let entries = ["First", "Second", "Third", "Fourth", "Fifth"];
+        for index in 0..entries.len() {
+            assert_fits(&Menu::new(&entries).initial(index).scroll_indicator(true));
+        }
+        let items = [
+            MenuItem::new("Settings").icon(&crate::bitmaps::COGGLE),
+            MenuItem::new("Quit")
+                .second_line("app")
+                .icon(&crate::bitmaps::DASHBOARD),
+        ];
+        assert_fits(&Menu::from_items(&items).scroll_indicator(true));
+        assert_fits(&SingleMessage::new("Hello"));
+        assert_fits(&MessageScroller::new("A message spanning a few pages"));
+        assert_fits(&AddressDisplay::new(
+            "0x71C7656EC7ab88b098defB751B7401B5f6d8976F",
+        ));
+    }
+
+    const RIGHT: [Event; 2] = [Event::Press(Button::Right), Event::Release(Button::Right)];
+    const BOTH: [Event; 2] = [Event::Press(Button::Both), Event::Release(Button::Both)];
+
+    #[test]
+    fn menu_right_right_both() {
+        let events = [RIGHT, RIGHT, BOTH].concat();
+        let selected = with_device(Device::NanoX, || {
+            Menu::new(&["First", "Second", "Third"]).show_with(&mut Script::new(&events))
+        });
+        assert_eq!(selected, Some(2));
+        assert!(!stub::take().is_empty());
+    }
+
+    #[test]
+    fn validator_script() {
+        let events = [RIGHT, BOTH].concat();
+        let approved = with_device(Device::NanoS, || {
+            Validator::new("Sign?").show_with(&mut Script::new(&events))
+        });
+        assert_eq!(approved, Some(true));
+    }
+
+    #[test]
+    fn timeout_while_held() {
+        let mut events = [Event::Ticker; 24];
+        events[0] = Event::Press(Button::Right);
+        events[20] = Event::Release(Button::Right);
+        let entries = ["First", "Second", "Third"];
+        let mut menu = Menu::new(&entries).auto_repeat(AutoRepeat::new());
+        let held = with_device(Device::NanoX, || {
+            run(&mut menu, &mut Script::new(&events), Timeout::new(500))
+        });
+        assert_eq!(held, Ok(None));
+        // Scrolled while held
+        assert_eq!(menu.index, 2);
+        let idle = with_device(Device::NanoX, || {
+            run(
+                &mut menu,
+                &mut Script::new(&[Event::Ticker; 5]),
+                Timeout::new(500),
+            )
+        });
+        assert_eq!(idle, Err(TimedOut));
+    }
+
+    #[test]
+    fn timeout_not_reset_by_stale_release() {
+        let mut events = [Event::Ticker; 6];
+        events[2] = Event::Release(Button::Left);
+        let timed_out = with_device(Device::NanoX, || {
+            run(
+                &mut Validator::new("Sign?"),
+                &mut Script::new(&events),
+                Timeout::new(500),
+            )
+        });
+        assert_eq!(timed_out, Err(TimedOut));
+    }
+
+    #[test]
+    fn settings_toggle_then_back() {
+        use crate::settings::{Setting, Settings};
+        let events = [BOTH, RIGHT, BOTH].concat();
+        let mut state = [0u8; 1];
+        let left = with_device(Device::NanoX, || {
+            Settings::new(&[Setting::toggle("Blind signing")], &mut state)
+                .show_with(&mut Script::new(&events))
+        });
+        assert_eq!(left, Some(()));
+        assert_eq!(state, [1]);
+    }
+
+    #[test]
+    fn item_menu_shows_non_selectable_neighbours() {
+        let items = [
+            MenuItem::new("Accounts").kind(ItemKind::Header),
+            MenuItem::new("Main").icon(&crate::bitmaps::COGGLE),
+            MenuItem::new("Polygon").kind(ItemKind::Disabled),
+            MenuItem::new("Savings"),
+        ];
+        let menu = Menu::from_items(&items);
+        assert_fits(&menu);
+        // One underline and one strike through, Nano S only has room for the header
+        for (device, rules) in [(Device::NanoS, 1), (Device::NanoX, 2)] {
+            let drawn = with_device(device, || {
+                stub::take();
+                menu.render();
+                stub::take()
+            });
+            let lines = drawn
+                .iter()
+                .filter(|area| area.height == 1 && area.width > 1);
+            assert_eq!(lines.count(), rules, "{:?}", device);
+        }
+    }
+
+    #[test]
+    fn item_cursor_skips_disabled() {
+        let items = [
+            MenuItem::new("Main"),
+            MenuItem::new("Polygon").kind(ItemKind::Disabled),
+            MenuItem::new("Savings"),
+        ];
+        let events = [RIGHT, BOTH].concat();
+        let selected = with_device(Device::NanoX, || {
+            Menu::from_items(&items).show_with(&mut Script::new(&events))
+        });
+        assert_eq!(selected, Some(2));
+    }
+
+    #[test]
+    fn nothing_selectable() {
+        let items = [MenuItem::new("Accounts").kind(ItemKind::Header)];
+        let selected = with_device(Device::NanoX, || {
+            Menu::from_items(&items).show_with(&mut Script::new(&BOTH))
+        });
+        assert_eq!(selected, None);
+    }
+
+    #[test]
+    fn empty_scroller_returns_at_once() {
+        stub::take();
+        assert_eq!(
+            MessageScroller::new("").show_with(&mut Script::new(&[])),
+            Some(())
+        );
+        assert!(stub::take().is_empty());
+    }
+
+    #[test]
+    fn item_writer() {
+        let mut buf = [0u8; ITEM_BUF_LEN];
+        let text = ItemWriter::new(&mut buf)
+            .str("Word #")
+            .num(0)
+            .str("/")
+            .num(1024)
+            .finish();
+        assert_eq!(text, "Word #0/1024");
+        let mut buf = [0u8; 7];
+        assert_eq!(
+            ItemWriter::new(&mut buf).str("Account #").num(12).finish(),
+            "Account"
+        );
+        // Truncated within a two-byte character
+        let mut buf = [0u8; 2];
+        assert_eq!(ItemWriter::new(&mut buf).str("aé").finish(), "a");
+    }
+
+    #[test]
+    fn script_runs_out() {
+        let shown = with_device(Device::NanoSPlus, || {
+            SingleMessage::new("Hello").show_with(&mut Script::new(&RIGHT[..1]))
+        });
+        assert_eq!(shown, None);
     }
 }
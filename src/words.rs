@@ -0,0 +1,686 @@
+use core::cell::Cell;
+use core::ops::Range;
+
+use crate::bagls::{Arrow, Label, CHECKMARK_ICON};
+use crate::event::{Button, Event, EventSource};
+use crate::executor::Events;
+use crate::layout::{Area, Draw};
+use crate::pic::{pic_slice, pic_str};
+use crate::screen::ScreenProfile;
+use crate::stack::{Child, HStack, VStack};
+use crate::ui::{
+    clear_screen, Gadget, GadgetExt, ItemKind, ItemWriter, Menu, MenuSource, SephEvents,
+    ITEM_BUF_LEN,
+};
+
+/// Longest prefix that can be typed
+const MAX_PREFIX: usize = 16;
+
+/// Pick a word of a sorted wordlist, such as BIP39 or SLIP39:
+/// letters are chosen one by one, only those leading to a word
+/// being offered, until few enough words are left to be listed
+/// in a menu.
+#[derive(Copy, Clone)]
+pub struct WordPicker<'a> {
+    words: &'a [&'a str],
+    title: &'a str,
+    threshold: usize,
+    prefix: [u8; MAX_PREFIX],
+    len: usize,
+    /// Position in the wheel of letters
+    position: usize,
+    /// Range of the words listed in the menu, if any
+    listed: Option<(usize, usize)>,
+    /// Menu of the listed words, see `Menu::with_source`
+    menu: Menu<'a, Candidates<'a>>,
+}
+
+impl<'a> WordPicker<'a> {
+    /// `words` must be sorted
+    pub fn new(words: &'a [&'a str]) -> Self {
+        WordPicker {
+            words: pic_slice(words),
+            title: "",
+            threshold: 6,
+            prefix: [0; MAX_PREFIX],
+            len: 0,
+            position: 0,
+            listed: None,
+            menu: Menu::from_source(&NO_CANDIDATES),
+        }
+    }
+
+    /// Text displayed while no letter is chosen, e.g. "Enter word #3"
+    pub const fn title(self, title: &'a str) -> Self {
+        WordPicker { title, ..self }
+    }
+
+    /// List the words in a menu once at most `threshold` are left,
+    /// or once the chosen letters form a complete word
+    pub const fn menu_threshold(self, threshold: usize) -> Self {
+        WordPicker { threshold, ..self }
+    }
+
+    /// Returns the index of the word in the wordlist
+    pub fn show(&self) -> usize {
+        self.show_with(&mut SephEvents::new()).unwrap_or(0)
+    }
+
+    /// Words starting with `prefix`, which are contiguous in a sorted list
+    fn matching(&self, prefix: &[u8]) -> Range<usize> {
+        let start = self
+            .words
+            .partition_point(|w| pic_str(w).as_bytes() < prefix);
+        let count =
+            self.words[start..].partition_point(|w| pic_str(w).as_bytes().starts_with(prefix));
+        start..start + count
+    }
+
+    fn prefix(&self) -> &[u8] {
+        &self.prefix[..self.len]
+    }
+
+    /// Letters which can follow the prefix, taken from the wordlist
+    /// in its order. Letters which do not fit in the prefix are left out.
+    fn letters(&self) -> impl Iterator<Item = char> + '_ {
+        let room = MAX_PREFIX - self.len;
+        let mut previous = None;
+        self.words[self.matching(self.prefix())]
+            .iter()
+            .filter_map(move |w| {
+                let next = pic_str(w).get(self.len..)?.chars().next()?;
+                // Sorted words sharing the prefix have their next letters grouped
+                let new = previous != Some(next);
+                previous = Some(next);
+                new.then_some(next)
+            })
+            .filter(move |c| c.len_utf8() <= room)
+    }
+
+    fn wheel_len(&self) -> usize {
+        self.letters().count() + (self.len > 0) as usize
+    }
+
+    /// Letter at `position`, `None` standing for "Delete"
+    fn choice(&self) -> Option<char> {
+        self.letters().nth(self.position)
+    }
+
+    /// Whether the prefix is word `index` of the wordlist
+    fn is_word(&self, index: usize) -> bool {
+        self.words
+            .get(index)
+            .is_some_and(|w| pic_str(w).as_bytes() == self.prefix())
+    }
+
+    /// Remove the last letter
+    fn back(&mut self) {
+        let prefix = core::str::from_utf8(self.prefix()).unwrap_or("");
+        self.len = prefix.char_indices().last().map_or(0, |(last, _)| last);
+        self.position = 0;
+    }
+
+    /// Menu entries for the words in `start..end`
+    fn candidates(&self, (start, end): (usize, usize)) -> Candidates<'a> {
+        Candidates(&self.words[start..end])
+    }
+
+    fn render_letters(&self) {
+        clear_screen();
+        let mut buf = [0u8; MAX_PREFIX + 1];
+        let mut count_buf = [0u8; ITEM_BUF_LEN];
+        let typed = match core::str::from_utf8(self.prefix()) {
+            Ok(prefix) if self.len > 0 => ItemWriter::new(&mut buf).str(prefix).str("_").finish(),
+            _ => self.title,
+        };
+        let mut letter = [0u8; 4];
+        let choice = match self.choice() {
+            Some(c) => c.encode_utf8(&mut letter),
+            None => "Delete",
+        };
+        let count = self.matching(self.prefix()).len();
+        let count = ItemWriter::new(&mut count_buf)
+            .num(count)
+            .str(" words")
+            .finish();
+        let labels = [
+            Label::from(typed),
+            Label::from(choice).bold(),
+            Label::from(count),
+        ];
+        let lines = labels.each_ref().map(Child::Label);
+        // No room for the count on Nano S
+        let shown = lines.len().min(ScreenProfile::current().max_lines);
+        VStack::new(&lines[..shown]).place(Area::screen());
+        Arrow::Left.icon().display();
+        Arrow::Right.icon().display();
+        crate::screen_util::screen_update();
+    }
+
+    /// Returns the range of the words to list in the menu
+    fn handle_letters(&mut self, event: Event) -> Option<Range<usize>> {
+        let len = self.wheel_len().max(1);
+        match event {
+            Event::Press(Button::Left) => Arrow::Left.pressed().instant_display(),
+            Event::Press(Button::Right) => Arrow::Right.pressed().instant_display(),
+            Event::Release(Button::Left) => {
+                self.position = (self.position + len - 1) % len;
+                self.render_letters();
+            }
+            Event::Release(Button::Right) => {
+                self.position = (self.position + 1) % len;
+                self.render_letters();
+            }
+            Event::Release(Button::Both) => {
+                match self.choice() {
+                    Some(c) => {
+                        self.len += c.encode_utf8(&mut self.prefix[self.len..]).len();
+                        self.position = 0;
+                        let range = self.matching(self.prefix());
+                        // A complete word could not be picked otherwise
+                        // when it starts more than `threshold` words
+                        if range.len() <= self.threshold || self.is_word(range.start) {
+                            return Some(range);
+                        }
+                    }
+                    None => self.back(),
+                }
+                self.render_letters();
+            }
+            _ => (),
+        }
+        None
+    }
+}
+
+impl Gadget for WordPicker<'_> {
+    /// Index of the word in the wordlist
+    type Outcome = usize;
+
+    fn render(&self) {
+        match self.listed {
+            Some(listed) => self.menu.with_source(&self.candidates(listed)).render(),
+            None => self.render_letters(),
+        }
+    }
+
+    fn handle(&mut self, event: Event) -> Option<usize> {
+        let Some((start, end)) = self.listed else {
+            let range = self.handle_letters(event)?;
+            self.listed = Some((range.start, range.end));
+            self.menu = Menu::from_source(&NO_CANDIDATES);
+            self.render();
+            return None;
+        };
+        let candidates = self.candidates((start, end));
+        let mut menu = self.menu.with_source(&candidates);
+        let index = menu.handle(event);
+        self.menu = menu.with_source(&NO_CANDIDATES);
+        // The last entry is "Back"
+        match start + index? {
+            word if word < end => Some(word),
+            _ => {
+                self.listed = None;
+                self.back();
+                self.render();
+                None
+            }
+        }
+    }
+}
+
+/// Menu entries for the remaining words, followed by "Back"
+#[derive(Copy, Clone)]
+struct Candidates<'a>(&'a [&'a str]);
+
+/// Source of `WordPicker::menu` between events
+const NO_CANDIDATES: Candidates<'static> = Candidates(&[]);
+
+impl MenuSource for Candidates<'_> {
+    fn len(&self) -> usize {
+        self.0.len() + 1
+    }
+
+    fn item<'b>(&'b self, index: usize, _buf: &'b mut [u8; ITEM_BUF_LEN]) -> &'b str {
+        self.0.get(index).map_or("Back", |word| pic_str(word))
+    }
+}
+
+/// Words of a mnemonic, one per screen, with their position.
+/// A last page confirms that the words were written down.
+#[derive(Copy, Clone)]
+pub struct MnemonicDisplay<'a> {
+    words: &'a [&'a str],
+    page: usize,
+}
+
+impl<'a> MnemonicDisplay<'a> {
+    pub fn new(words: &'a [&'a str]) -> Self {
+        MnemonicDisplay {
+            words: pic_slice(words),
+            page: 0,
+        }
+    }
+
+    pub fn show(&self) {
+        self.show_with(&mut SephEvents::new());
+    }
+}
+
+impl Gadget for MnemonicDisplay<'_> {
+    type Outcome = ();
+
+    fn render(&self) {
+        clear_screen();
+        match self.words.get(self.page) {
+            Some(word) => {
+                let mut buf = [0u8; ITEM_BUF_LEN];
+                let header = ItemWriter::new(&mut buf)
+                    .str("Word #")
+                    .num(self.page + 1)
+                    .str(" / ")
+                    .num(self.words.len())
+                    .finish();
+                let labels = [Label::from(header), Label::from(pic_str(word)).large()];
+                let lines = labels.each_ref().map(Child::Label);
+                VStack::new(&lines).place(Area::screen());
+                Arrow::Right.icon().display();
+            }
+            None => {
+                let icon = CHECKMARK_ICON;
+                let label = Label::from("Done");
+                let children = [Child::Icon(&icon), Child::Label(&label)];
+                HStack::new(&children)
+                    .spacing(crate::PADDING * 3)
+                    .place(Area::screen());
+            }
+        }
+        if self.page > 0 {
+            Arrow::Left.icon().display();
+        }
+        crate::screen_util::screen_update();
+    }
+
+    fn handle(&mut self, event: Event) -> Option<()> {
+        match event {
+            Event::Press(Button::Left) => Arrow::Left.pressed().instant_display(),
+            Event::Press(Button::Right) => Arrow::Right.pressed().instant_display(),
+            Event::Release(Button::Left) => {
+                self.page = self.page.saturating_sub(1);
+                self.render();
+            }
+            Event::Release(Button::Right) => {
+                if self.page < self.words.len() {
+                    self.page += 1;
+                }
+                self.render();
+            }
+            Event::Release(Button::Both) if self.page == self.words.len() => return Some(()),
+            _ => (),
+        }
+        None
+    }
+}
+
+/// Largest number of words offered by `MnemonicQuiz` for a position
+pub const MAX_CANDIDATES: usize = 6;
+
+/// Largest number of positions asked by `MnemonicQuiz`
+pub const MAX_ROUNDS: usize = 24;
+
+/// Check that the user wrote the mnemonic down: for random
+/// positions, the right word must be picked among several
+/// words of the mnemonic
+#[derive(Clone)]
+pub struct MnemonicQuiz<'a> {
+    words: &'a [&'a str],
+    candidates: usize,
+    rounds: usize,
+    /// Rounds answered right so far
+    round: usize,
+    /// Positions of the rounds answered so far
+    asked: [usize; MAX_ROUNDS],
+    /// Question of the current round, drawn once displayed
+    /// so that it follows the builders
+    question: Cell<Option<Question<'a>>>,
+    /// Menu of the current round, see `Menu::with_source`
+    menu: Menu<'a, Question<'a>>,
+}
+
+impl<'a> MnemonicQuiz<'a> {
+    pub fn new(words: &'a [&'a str]) -> Self {
+        MnemonicQuiz {
+            words: pic_slice(words),
+            candidates: 3,
+            rounds: 1,
+            round: 0,
+            asked: [0; MAX_ROUNDS],
+            question: Cell::new(None),
+            menu: Menu::from_source(&NO_QUESTION),
+        }
+    }
+
+    /// Number of words offered for each position, at most `MAX_CANDIDATES`
+    pub const fn candidates(self, candidates: usize) -> Self {
+        MnemonicQuiz { candidates, ..self }
+    }
+
+    /// Number of positions asked for, each one at most once.
+    /// Limited to `MAX_ROUNDS` and to the number of words.
+    pub const fn rounds(self, rounds: usize) -> Self {
+        MnemonicQuiz { rounds, ..self }
+    }
+
+    /// Returns whether every word was picked right
+    pub fn check(&self) -> bool {
+        self.check_with(&mut SephEvents::new()).unwrap_or(false)
+    }
+
+    /// Same as `check`, reading events from `source`.
+    /// Returns `None` if `source` runs out of events first.
+    pub fn check_with(&self, source: &mut impl EventSource) -> Option<bool> {
+        self.clone().show_with(source)
+    }
+
+    /// Same as `check`, awaiting events from `events`
+    pub async fn check_async(&self, events: &Events) -> bool {
+        self.clone().show_async(events).await
+    }
+
+    fn round_count(&self) -> usize {
+        self.rounds.min(self.words.len()).min(MAX_ROUNDS)
+    }
+
+    /// Question of the current round
+    fn current(&self) -> Question<'a> {
+        match self.question.get() {
+            Some(question) => question,
+            None => {
+                let question = self.question(&self.asked[..self.round]);
+                self.question.set(Some(question));
+                question
+            }
+        }
+    }
+
+    /// Random position not `asked` yet, and the candidates in random order
+    fn question(&self, asked: &[usize]) -> Question<'a> {
+        let len = self.words.len();
+        let position = (0..len)
+            .filter(|position| !asked.contains(position))
+            .nth(random_below(len.saturating_sub(asked.len())))
+            .unwrap_or(0);
+        let mut question = Question {
+            words: self.words,
+            position,
+            candidates: [position; MAX_CANDIDATES],
+            count: 1,
+        };
+        let wanted = self.candidates.clamp(1, MAX_CANDIDATES);
+        // Other positions, starting at a random one,
+        // skipping words already offered
+        let offset = random_below(len);
+        for i in 0..len {
+            if question.count == wanted {
+                break;
+            }
+            let other = (offset + i) % len;
+            let word = pic_str(self.words[other]);
+            let offered = question.candidates[..question.count]
+                .iter()
+                .any(|&c| pic_str(self.words[c]) == word);
+            if !offered {
+                question.candidates[question.count] = other;
+                question.count += 1;
+            }
+        }
+        // Fisher-Yates shuffle
+        for i in (1..question.count).rev() {
+            question.candidates.swap(i, random_below(i + 1));
+        }
+        question
+    }
+}
+
+impl Gadget for MnemonicQuiz<'_> {
+    /// Whether every word was picked right
+    type Outcome = bool;
+
+    fn render(&self) {
+        self.menu.with_source(&self.current()).render();
+    }
+
+    fn handle(&mut self, event: Event) -> Option<bool> {
+        let question = self.current();
+        let mut menu = self.menu.with_source(&question);
+        let index = menu.handle(event);
+        self.menu = menu.with_source(&NO_QUESTION);
+        if !question.is_answer(index?) {
+            return Some(false);
+        }
+        self.asked[self.round] = question.position;
+        self.round += 1;
+        if self.round == self.round_count() {
+            return Some(true);
+        }
+        self.question.set(None);
+        self.menu = Menu::from_source(&NO_QUESTION);
+        self.render();
+        None
+    }
+
+    fn immediate(&self) -> Option<bool> {
+        (self.round >= self.round_count()).then_some(true)
+    }
+}
+
+/// Menu of a quiz round: the question as a header,
+/// followed by the candidates
+#[derive(Copy, Clone)]
+struct Question<'a> {
+    words: &'a [&'a str],
+    position: usize,
+    /// Positions of the candidate words
+    candidates: [usize; MAX_CANDIDATES],
+    count: usize,
+}
+
+/// Source of `MnemonicQuiz::menu` between events
+const NO_QUESTION: Question<'static> = Question {
+    words: &[],
+    position: 0,
+    candidates: [0; MAX_CANDIDATES],
+    count: 0,
+};
+
+impl Question<'_> {
+    /// Whether menu entry `index` is the right word
+    fn is_answer(&self, index: usize) -> bool {
+        let answer = pic_str(self.words[self.position]);
+        match index
+            .checked_sub(1)
+            .and_then(|i| self.candidates[..self.count].get(i))
+        {
+            Some(&c) => pic_str(self.words[c]) == answer,
+            None => false,
+        }
+    }
+}
+
+impl MenuSource for Question<'_> {
+    fn len(&self) -> usize {
+        self.count + 1
+    }
+
+    fn item<'b>(&'b self, index: usize, buf: &'b mut [u8; ITEM_BUF_LEN]) -> &'b str {
+        match index.checked_sub(1) {
+            None => ItemWriter::new(buf)
+                .str("Word #")
+                .num(self.position + 1)
+                .str("?")
+                .finish(),
+            Some(i) => pic_str(self.words[self.candidates[i]]),
+        }
+    }
+
+    fn kind(&self, index: usize) -> ItemKind {
+        match index {
+            0 => ItemKind::Header,
+            _ => ItemKind::Selectable,
+        }
+    }
+}
+
+/// Uniform random number below `bound`, 0 if `bound` is 0
+fn random_below(bound: usize) -> usize {
+    let bound = bound as u32;
+    if bound == 0 {
+        return 0;
+    }
+    // Reject the values which would bias the modulo
+    let limit = u32::MAX - u32::MAX % bound;
+    loop {
+        let value = random_u32();
+        if value < limit {
+            return (value % bound) as usize;
+        }
+    }
+}
+
+/// Random value from the SDK.
+/// Host tests always draw 0, so that quizzes are predictable.
+fn random_u32() -> u32 {
+    #[cfg(test)]
+    {
+        0
+    }
+    #[cfg(not(test))]
+    {
+        let mut bytes = [0u8; 4];
+        unsafe { ledger_sdk_sys::cx_rng_no_throw(bytes.as_mut_ptr(), bytes.len()) };
+        u32::from_le_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Script;
+    use crate::screen::{with_device, Device};
+    use crate::ui::tests::assert_fits;
+
+    const WORDS: [&str; 6] = ["cab", "can", "canal", "candy", "canoe", "car"];
+
+    #[test]
+    fn letters_fit_every_device() {
+        let mut picker = WordPicker::new(&WORDS).title("Enter word #1");
+        assert_fits(&picker);
+        picker.prefix[..2].copy_from_slice(b"ca");
+        picker.len = 2;
+        assert_fits(&picker);
+        picker.listed = Some((1, 5));
+        assert_fits(&picker);
+    }
+
+    #[test]
+    fn mnemonic_fits_every_device() {
+        let words = ["abandon", "wednesday", "zoo"];
+        let mut display = MnemonicDisplay::new(&words);
+        for page in 0..=words.len() {
+            display.page = page;
+            assert_fits(&display);
+        }
+    }
+
+    #[test]
+    fn complete_word_starting_many() {
+        const RIGHT: [Event; 2] = [Event::Press(Button::Right), Event::Release(Button::Right)];
+        const BOTH: [Event; 2] = [Event::Press(Button::Both), Event::Release(Button::Both)];
+        // "c", "a", then "n" after "b": "can" starts 4 words
+        let events = [BOTH, BOTH, RIGHT, BOTH, BOTH].concat();
+        let picked = with_device(Device::NanoX, || {
+            WordPicker::new(&WORDS)
+                .menu_threshold(2)
+                .show_with(&mut Script::new(&events))
+        });
+        assert_eq!(picked, Some(1));
+    }
+
+    #[test]
+    fn letters_from_the_wordlist() {
+        let words = ["Alpha", "beta", "bêta", "z9", "z90"];
+        let mut picker = WordPicker::new(&words);
+        assert!(picker.letters().eq(['A', 'b', 'z']));
+        picker.len = 1;
+        picker.prefix[0] = b'b';
+        assert!(picker.letters().eq(['e', 'ê']));
+        picker.len = "bê".len();
+        picker.prefix[..picker.len].copy_from_slice("bê".as_bytes());
+        assert!(picker.letters().eq(['t']));
+        picker.back();
+        assert_eq!(picker.prefix(), b"b");
+        picker.prefix[..3].copy_from_slice(b"z90");
+        picker.len = 2;
+        // "z9" is complete, only "z90" goes on
+        assert!(picker.letters().eq(['0']));
+    }
+
+    #[test]
+    fn back_from_the_menu() {
+        const RIGHT: [Event; 2] = [Event::Press(Button::Right), Event::Release(Button::Right)];
+        const BOTH: [Event; 2] = [Event::Press(Button::Both), Event::Release(Button::Both)];
+        // "cab" listed, "Back", then "car" listed
+        let events = [BOTH, BOTH, BOTH, RIGHT, BOTH, RIGHT, RIGHT, BOTH, BOTH].concat();
+        let picked = with_device(Device::NanoX, || {
+            WordPicker::new(&WORDS)
+                .menu_threshold(2)
+                .show_with(&mut Script::new(&events))
+        });
+        assert_eq!(picked, Some(5));
+    }
+
+    const QUIZ: [&str; 4] = ["apple", "bread", "apple", "dance"];
+
+    #[test]
+    fn positions_asked_once() {
+        let quiz = MnemonicQuiz::new(&QUIZ);
+        let mut asked = [0; MAX_ROUNDS];
+        for round in 0..QUIZ.len() {
+            let position = quiz.question(&asked[..round]).position;
+            assert!(!asked[..round].contains(&position));
+            asked[round] = position;
+        }
+        assert_eq!(quiz.rounds(10).round_count(), QUIZ.len());
+    }
+
+    #[test]
+    fn repeated_words_offered_once() {
+        let question = MnemonicQuiz::new(&QUIZ).candidates(4).question(&[]);
+        assert_eq!(question.position, 0);
+        // "apple" is also the third word
+        assert_eq!(question.count, 3);
+        let answers = (0..question.len()).filter(|&index| question.is_answer(index));
+        assert_eq!(answers.count(), 1);
+        // The header is not an answer
+        assert!(!question.is_answer(0));
+        assert!(!question.is_answer(question.len()));
+    }
+
+    #[test]
+    fn quiz_pass_and_fail() {
+        const RIGHT: [Event; 2] = [Event::Press(Button::Right), Event::Release(Button::Right)];
+        const BOTH: [Event; 2] = [Event::Press(Button::Both), Event::Release(Button::Both)];
+        let quiz = MnemonicQuiz::new(&QUIZ).rounds(2);
+        assert_fits(&quiz);
+        let check = |events: &[Event]| {
+            with_device(Device::NanoX, || quiz.check_with(&mut Script::new(events)))
+        };
+        // The shuffle moves the answer last when drawing 0
+        let pass = [RIGHT, RIGHT, BOTH, RIGHT, RIGHT, BOTH].concat();
+        assert_eq!(check(&pass), Some(true));
+        assert_eq!(check(&[RIGHT, BOTH].concat()), Some(false));
+        assert_eq!(check(&pass[..6]), None);
+        let empty = MnemonicQuiz::new(&[]);
+        assert_eq!(empty.check_with(&mut Script::new(&[])), Some(true));
+    }
+}